@@ -33,57 +33,514 @@
 //! PRs adding new networks for the existing currencies (e.g. regtest) and constants not yet
 //! included are very welcome. Please provide credible sources for magic bytes etc. in comments
 //! to make review easier.
+//!
+//! The `std` feature is enabled by default. Disabling it builds the crate as `no_std` with the
+//! `alloc` feature providing the allocations networks need; without `std`
+//! `Network::all_by_currency`, `Network::print_summary` and `Network::btc_to_satoshis` aren't
+//! available. Note that `bitcoin_hashes` 0.1 itself still depends on `std`.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs)]
 #![deny(non_upper_case_globals)]
 #![deny(non_camel_case_types)]
 #![deny(non_snake_case)]
 #![deny(unused_mut)]
 
+#[cfg(not(feature = "alloc"))]
+compile_error!("the `alloc` feature is required for `Arc`, `String` and `Vec`");
+
+#[macro_use]
+extern crate alloc;
 extern crate bitcoin_hashes;
+#[cfg(feature = "std")]
+extern crate core;
 
-use bitcoin_hashes::sha256d;
-use std::{fmt, ops};
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use bitcoin_hashes::{sha256d, Hash};
+use core::convert::TryFrom;
+use core::{cmp, fmt, hash, ops, str};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::ptr;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(feature = "std")]
+use std::sync::{Once, PoisonError, RwLock};
 
 pub mod networks;
 
+/// Constructors of all networks built into this crate
+const BUILT_IN_NETWORKS: &[fn() -> Network] = &[
+    Network::bitcoin,
+    Network::bitcoin_testnet,
+    Network::bitcoin_testnet4,
+    Network::bitcoin_signet,
+    Network::bitcoin_regtest,
+    Network::litecoin,
+    Network::litecoin_testnet,
+    Network::dogecoin,
+    Network::dogecoin_testnet,
+    Network::namecoin,
+    Network::vertcoin,
+    Network::vertcoin_testnet,
+    Network::groestlcoin,
+    Network::ravencoin,
+    Network::ravencoin_testnet,
+    Network::digibyte,
+    Network::feathercoin,
+    Network::feathercoin_testnet,
+    Network::monacoin,
+    Network::monacoin_testnet,
+];
+
+/// Constructors of the bitcoin networks, in the order returned by `Network::all_bitcoin`
+const BITCOIN_NETWORKS: &[fn() -> Network] = &[
+    Network::bitcoin,
+    Network::bitcoin_testnet,
+    Network::bitcoin_testnet4,
+    Network::bitcoin_signet,
+    Network::bitcoin_regtest,
+];
+
 /// Represents a bitcoin-like network for which it can provide encoding , network and consensus
 /// constants.
-pub struct Network(Box<NetworkConstants>);
+#[derive(Clone)]
+pub struct Network(NetworkKind);
+
+/// Lists the networks built into this crate, networks defined elsewhere are `Custom`. Only
+/// `Custom` networks allocate.
+#[derive(Clone)]
+pub enum NetworkKind {
+    /// The bitcoin mainnet
+    Bitcoin,
+    /// The bitcoin testnet
+    BitcoinTestnet,
+    /// The bitcoin testnet4
+    BitcoinTestnet4,
+    /// The default bitcoin signet
+    BitcoinSignet,
+    /// The bitcoin regtest
+    BitcoinRegtest,
+    /// The litecoin mainnet
+    Litecoin,
+    /// The litecoin testnet
+    LitecoinTestnet,
+    /// The dogecoin mainnet
+    Dogecoin,
+    /// The dogecoin testnet
+    DogecoinTestnet,
+    /// The namecoin mainnet
+    Namecoin,
+    /// The vertcoin mainnet
+    Vertcoin,
+    /// The vertcoin testnet
+    VertcoinTestnet,
+    /// The groestlcoin mainnet
+    Groestlcoin,
+    /// The ravencoin mainnet
+    Ravencoin,
+    /// The ravencoin testnet
+    RavencoinTestnet,
+    /// The digibyte mainnet
+    DigiByte,
+    /// The feathercoin mainnet
+    Feathercoin,
+    /// The feathercoin testnet
+    FeathercoinTestnet,
+    /// The monacoin mainnet
+    Monacoin,
+    /// The monacoin testnet
+    MonacoinTestnet,
+    /// A network defined outside of this crate
+    Custom(Arc<dyn NetworkConstants>),
+}
+
+impl NetworkKind {
+    /// Returns the constants of the network
+    pub fn constants(&self) -> &(dyn NetworkConstants + 'static) {
+        match *self {
+            NetworkKind::Bitcoin => &networks::Bitcoin {},
+            NetworkKind::BitcoinTestnet => &networks::BitcoinTestnet {},
+            NetworkKind::BitcoinTestnet4 => &networks::BitcoinTestnet4 {},
+            NetworkKind::BitcoinSignet => &networks::BitcoinSignet {},
+            NetworkKind::BitcoinRegtest => &networks::BitcoinRegtest {},
+            NetworkKind::Litecoin => &networks::Litecoin {},
+            NetworkKind::LitecoinTestnet => &networks::LitecoinTestnet {},
+            NetworkKind::Dogecoin => &networks::Dogecoin {},
+            NetworkKind::DogecoinTestnet => &networks::DogecoinTestnet {},
+            NetworkKind::Namecoin => &networks::Namecoin {},
+            NetworkKind::Vertcoin => &networks::Vertcoin {},
+            NetworkKind::VertcoinTestnet => &networks::VertcoinTestnet {},
+            NetworkKind::Groestlcoin => &networks::Groestlcoin {},
+            NetworkKind::Ravencoin => &networks::Ravencoin {},
+            NetworkKind::RavencoinTestnet => &networks::RavencoinTestnet {},
+            NetworkKind::DigiByte => &networks::DigiByte {},
+            NetworkKind::Feathercoin => &networks::Feathercoin {},
+            NetworkKind::FeathercoinTestnet => &networks::FeathercoinTestnet {},
+            NetworkKind::Monacoin => &networks::Monacoin {},
+            NetworkKind::MonacoinTestnet => &networks::MonacoinTestnet {},
+            NetworkKind::Custom(ref constants) => &**constants,
+        }
+    }
+}
+
+impl fmt::Debug for NetworkKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NetworkKind::Bitcoin => f.write_str("Bitcoin"),
+            NetworkKind::BitcoinTestnet => f.write_str("BitcoinTestnet"),
+            NetworkKind::BitcoinTestnet4 => f.write_str("BitcoinTestnet4"),
+            NetworkKind::BitcoinSignet => f.write_str("BitcoinSignet"),
+            NetworkKind::BitcoinRegtest => f.write_str("BitcoinRegtest"),
+            NetworkKind::Litecoin => f.write_str("Litecoin"),
+            NetworkKind::LitecoinTestnet => f.write_str("LitecoinTestnet"),
+            NetworkKind::Dogecoin => f.write_str("Dogecoin"),
+            NetworkKind::DogecoinTestnet => f.write_str("DogecoinTestnet"),
+            NetworkKind::Namecoin => f.write_str("Namecoin"),
+            NetworkKind::Vertcoin => f.write_str("Vertcoin"),
+            NetworkKind::VertcoinTestnet => f.write_str("VertcoinTestnet"),
+            NetworkKind::Groestlcoin => f.write_str("Groestlcoin"),
+            NetworkKind::Ravencoin => f.write_str("Ravencoin"),
+            NetworkKind::RavencoinTestnet => f.write_str("RavencoinTestnet"),
+            NetworkKind::DigiByte => f.write_str("DigiByte"),
+            NetworkKind::Feathercoin => f.write_str("Feathercoin"),
+            NetworkKind::FeathercoinTestnet => f.write_str("FeathercoinTestnet"),
+            NetworkKind::Monacoin => f.write_str("Monacoin"),
+            NetworkKind::MonacoinTestnet => f.write_str("MonacoinTestnet"),
+            NetworkKind::Custom(ref constants) => write!(f, "Custom({})", constants.name()),
+        }
+    }
+}
 
 impl Network {
     /// Create a net `Network` object from a trait object that provides network constants
-    pub fn from_box(trait_obj: Box<NetworkConstants>) -> Network {
-        Network(trait_obj)
+    pub fn from_box(trait_obj: Box<dyn NetworkConstants>) -> Network {
+        Network(NetworkKind::Custom(Arc::from(trait_obj)))
+    }
+
+    /// Creates a `Network` object from its kind
+    pub fn from_kind(kind: NetworkKind) -> Network {
+        Network(kind)
+    }
+
+    /// Returns which network this is, built-in networks can be matched on exhaustively
+    pub fn kind(&self) -> &NetworkKind {
+        &self.0
     }
 
     /// Creates a `Network` object representing the bitcoin mainnet
     pub fn bitcoin() -> Network {
-        Self::from_box(networks::Bitcoin::new())
+        Network(NetworkKind::Bitcoin)
     }
 
     /// Creates a `Network` object representing the bitcoin testnet
     pub fn bitcoin_testnet() -> Network {
-        Self::from_box(networks::BitcoinTestnet::new())
+        Network(NetworkKind::BitcoinTestnet)
+    }
+
+    /// Creates a `Network` object representing the bitcoin testnet4
+    pub fn bitcoin_testnet4() -> Network {
+        Network(NetworkKind::BitcoinTestnet4)
+    }
+
+    /// Creates a `Network` object representing the default bitcoin signet
+    pub fn bitcoin_signet() -> Network {
+        Network(NetworkKind::BitcoinSignet)
+    }
+
+    /// Creates a `Network` object representing a bitcoin signet whose blocks have to satisfy the
+    /// `challenge` script, see [`BitcoinCustomSignet`](networks/struct.BitcoinCustomSignet.html)
+    pub fn bitcoin_signet_custom(challenge: Vec<u8>) -> Network {
+        Network::from_box(networks::BitcoinCustomSignet::new(challenge))
     }
 
     /// Creates a `Network` object representing the bitcoin regtest
     pub fn bitcoin_regtest() -> Network {
-        Self::from_box(networks::BitcoinRegtest::new())
+        Network(NetworkKind::BitcoinRegtest)
+    }
+
+    /// Creates a `Network` object representing the litecoin mainnet
+    pub fn litecoin() -> Network {
+        Network(NetworkKind::Litecoin)
+    }
+
+    /// Creates a `Network` object representing the litecoin testnet
+    pub fn litecoin_testnet() -> Network {
+        Network(NetworkKind::LitecoinTestnet)
+    }
+
+    /// Creates a `Network` object representing the dogecoin mainnet
+    pub fn dogecoin() -> Network {
+        Network(NetworkKind::Dogecoin)
+    }
+
+    /// Creates a `Network` object representing the dogecoin testnet
+    pub fn dogecoin_testnet() -> Network {
+        Network(NetworkKind::DogecoinTestnet)
+    }
+
+    /// Creates a `Network` object representing the namecoin mainnet
+    pub fn namecoin() -> Network {
+        Network(NetworkKind::Namecoin)
+    }
+
+    /// Creates a `Network` object representing the vertcoin mainnet
+    pub fn vertcoin() -> Network {
+        Network(NetworkKind::Vertcoin)
+    }
+
+    /// Creates a `Network` object representing the vertcoin testnet
+    pub fn vertcoin_testnet() -> Network {
+        Network(NetworkKind::VertcoinTestnet)
+    }
+
+    /// Creates a `Network` object representing the groestlcoin mainnet
+    pub fn groestlcoin() -> Network {
+        Network(NetworkKind::Groestlcoin)
+    }
+
+    /// Creates a `Network` object representing the ravencoin mainnet
+    pub fn ravencoin() -> Network {
+        Network(NetworkKind::Ravencoin)
+    }
+
+    /// Creates a `Network` object representing the ravencoin testnet
+    pub fn ravencoin_testnet() -> Network {
+        Network(NetworkKind::RavencoinTestnet)
+    }
+
+    /// Creates a `Network` object representing the digibyte mainnet
+    pub fn digibyte() -> Network {
+        Network(NetworkKind::DigiByte)
+    }
+
+    /// Creates a `Network` object representing the feathercoin mainnet
+    pub fn feathercoin() -> Network {
+        Network(NetworkKind::Feathercoin)
+    }
+
+    /// Creates a `Network` object representing the feathercoin testnet
+    pub fn feathercoin_testnet() -> Network {
+        Network(NetworkKind::FeathercoinTestnet)
+    }
+
+    /// Creates a `Network` object representing the monacoin mainnet
+    pub fn monacoin() -> Network {
+        Network(NetworkKind::Monacoin)
+    }
+
+    /// Creates a `Network` object representing the monacoin testnet
+    pub fn monacoin_testnet() -> Network {
+        Network(NetworkKind::MonacoinTestnet)
+    }
+
+    /// Returns the first network whose bech32 human readable part is `hrp`, see
+    /// [`find`](#method.find). The comparison is exact, so mixed or upper case HRPs have to be
+    /// lowercased first. Networks without bech32 support are never returned.
+    pub fn from_hrp(hrp: &str) -> Option<Network> {
+        if hrp.is_empty() {
+            return None;
+        }
+        Network::find(|n| n.hrp() == hrp)
+    }
+
+    /// Returns the first network whose bech32 human readable part is `hrp`, like
+    /// [`from_hrp`](#method.from_hrp) but with an error for use with `?`
+    pub fn try_from_hrp(hrp: &str) -> Result<Network, UnknownHrp> {
+        Network::from_hrp(hrp).ok_or_else(|| UnknownHrp(hrp.to_string()))
+    }
+
+    /// Returns the first network whose P2P message start bytes are `magic`, read little endian
+    /// like `magic()`, see [`find`](#method.find)
+    pub fn from_magic(magic: u32) -> Option<Network> {
+        Network::find(|n| n.magic() == magic)
+    }
+
+    /// Returns the first network whose P2P message start bytes are `magic`, like
+    /// [`from_magic`](#method.from_magic) but with an error for use with `?`
+    pub fn try_from_magic(magic: u32) -> Result<Network, UnknownMagic> {
+        Network::from_magic(magic).ok_or(UnknownMagic(magic))
     }
+
+    /// Returns the first network whose `genesis_block()` is `hash`, see [`find`](#method.find)
+    pub fn from_genesis_hash(hash: sha256d::Hash) -> Option<Network> {
+        Network::find(|n| n.genesis_block() == hash)
+    }
+
+    /// Returns the network whose `name()` is `name`, see [`find`](#method.find). Names are
+    /// compared case-sensitively, so "Bitcoin" doesn't match the bitcoin mainnet.
+    pub fn from_name(name: &str) -> Option<Network> {
+        Network::find(|n| n.name() == name)
+    }
+
+    /// Returns the first built-in network matching `predicate`. If there is none, the networks
+    /// registered with the global [`NetworkRegistry`](struct.NetworkRegistry.html) are searched
+    /// (requires the `std` feature).
+    pub fn find<F: Fn(&dyn NetworkConstants) -> bool>(predicate: F) -> Option<Network> {
+        BUILT_IN_NETWORKS
+            .iter()
+            .map(|constructor| constructor())
+            .find(|n| predicate(&**n))
+            .or_else(|| Network::find_registered(&predicate))
+    }
+
+    /// Returns an iterator over all built-in networks followed by the networks registered with the
+    /// global [`NetworkRegistry`](struct.NetworkRegistry.html) at the time of the call (requires
+    /// the `std` feature)
+    pub fn iter() -> impl Iterator<Item = Network> {
+        BUILT_IN_NETWORKS.iter().map(|constructor| constructor()).chain(Network::registered())
+    }
+
+    #[cfg(feature = "std")]
+    fn registered() -> Vec<Network> {
+        let registry = NetworkRegistry::global().read().unwrap_or_else(PoisonError::into_inner);
+        registry.networks.iter().map(|n| Network(NetworkKind::Custom(n.clone()))).collect()
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn registered() -> Vec<Network> {
+        Vec::new()
+    }
+
+    #[cfg(feature = "std")]
+    fn find_registered<F: Fn(&dyn NetworkConstants) -> bool>(predicate: F) -> Option<Network> {
+        NetworkRegistry::global().read().unwrap_or_else(PoisonError::into_inner).find(predicate)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn find_registered<F: Fn(&dyn NetworkConstants) -> bool>(_predicate: F) -> Option<Network> {
+        None
+    }
+
+    /// Returns an iterator over the bitcoin networks in a canonical order: mainnet, testnet,
+    /// testnet4, signet, regtest
+    pub fn all_bitcoin() -> impl Iterator<Item = Network> {
+        BITCOIN_NETWORKS.iter().map(|constructor| constructor())
+    }
+
+    /// Returns all built-in networks grouped by their `currency_name()`, each group sorted by
+    /// `network_type()`
+    #[cfg(feature = "std")]
+    pub fn all_by_currency() -> HashMap<String, Vec<Network>> {
+        let mut currencies: HashMap<String, Vec<Network>> = HashMap::new();
+        for constructor in BUILT_IN_NETWORKS {
+            let network = constructor();
+            currencies.entry(network.currency_name().to_owned()).or_default().push(network);
+        }
+        for networks in currencies.values_mut() {
+            networks.sort_by_key(|n| n.network_type());
+        }
+        currencies
+    }
+
+    /// Returns a human-friendly name of the network, e.g. "Bitcoin Testnet"
+    pub fn display_name(&self) -> String {
+        format!("{} {}", capitalize(self.currency_name()), capitalize(self.network_type().as_str()))
+    }
+
+    /// Returns the currency name together with the network type, e.g. "bitcoin (testnet)"
+    pub fn currency_and_type_display(&self) -> String {
+        format!("{} ({})", self.currency_name(), self.network_type())
+    }
+
+    /// Writes the currency name together with the network type to `f`, see
+    /// [`currency_and_type_display`](#method.currency_and_type_display)
+    pub fn fmt_with_type(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.currency_name(), self.network_type())
+    }
+
+    /// Prints a multi-line summary of the network's constants to stdout, see
+    /// [`to_summary_string`](#method.to_summary_string)
+    #[cfg(feature = "std")]
+    pub fn print_summary(&self) {
+        print!("{}", self.to_summary_string());
+    }
+
+    /// Returns a multi-line human-readable summary of the network's constants
+    pub fn to_summary_string(&self) -> String {
+        let params = self.chain_params();
+        let mut summary = String::new();
+        summary.push_str(&format!("name: {}\n", self.name()));
+        summary.push_str(&format!("network type: {}\n", self.network_type()));
+        summary.push_str(&format!("hrp: {}\n", self.hrp()));
+        summary.push_str(&format!("magic: 0x{:08x}\n", self.magic()));
+        summary.push_str(&format!("p2pkh prefix: 0x{:02x}\n", self.p2pkh_prefix()));
+        summary.push_str(&format!("p2sh prefix: 0x{:02x}\n", self.p2sh_prefix()));
+        summary.push_str(&format!("xpub prefix: 0x{}\n", hex(self.xpub_prefix())));
+        summary.push_str(&format!("xpriv prefix: 0x{}\n", hex(self.xpriv_prefix())));
+        summary.push_str(&format!("wif prefix: 0x{:02x}\n", self.wif_prefix()));
+        summary.push_str(&format!("genesis block: {}\n", self.genesis_block()));
+        summary.push_str(&format!("bip16 time: {}\n", params.bip16_time));
+        summary.push_str(&format!("bip34 height: {}\n", params.bip34_height));
+        summary.push_str(&format!("bip65 height: {}\n", params.bip65_height));
+        summary.push_str(&format!("bip66 height: {}\n", params.bip66_height));
+        summary.push_str(&format!("pow target spacing: {}s\n", params.pow_target_spacing));
+        summary.push_str(&format!("pow target timespan: {}s\n", params.pow_target_timespan));
+        summary.push_str(&format!("allow min difficulty blocks: {}\n", params.allow_min_difficulty_blocks));
+        summary.push_str(&format!("no pow retargeting: {}\n", params.no_pow_retargeting));
+        summary
+    }
+
+    /// Converts an amount of whole coins to the base unit, rounding to the nearest base unit.
+    /// Negative amounts result in 0.
+    #[cfg(feature = "std")]
+    pub fn btc_to_satoshis(&self, btc: f64) -> u64 {
+        (btc * self.satoshis_per_bitcoin() as f64).round() as u64
+    }
+
+    /// Converts an amount in the base unit to whole coins
+    pub fn satoshis_to_btc(&self, sats: u64) -> f64 {
+        sats as f64 / self.satoshis_per_bitcoin() as f64
+    }
+
+    /// Formats an amount in the base unit as whole coins with all decimal places, e.g.
+    /// "0.00100000 BTC"
+    pub fn format_amount(&self, satoshis: u64) -> String {
+        let unit = self.satoshis_per_bitcoin();
+        let mut decimals = 0;
+        let mut rest = unit;
+        while rest > 1 {
+            rest /= 10;
+            decimals += 1;
+        }
+
+        if decimals == 0 {
+            format!("{} {}", satoshis, self.ticker())
+        } else {
+            format!(
+                "{}.{:0width$} {}",
+                satoshis / unit,
+                satoshis % unit,
+                self.ticker(),
+                width = decimals
+            )
+        }
+    }
+}
+
+/// Formats bytes as lower case hex
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
 }
 
-impl Clone for Network {
-    fn clone(&self) -> Self {
-        Self::from_box(self.0.clone_boxed())
+/// Returns `s` with its first character in upper case
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
     }
 }
 
 impl ops::Deref for Network {
-    type Target = Box<NetworkConstants>;
+    type Target = dyn NetworkConstants;
 
-    fn deref(&self) -> &Box<NetworkConstants> {
-        &self.0
+    fn deref(&self) -> &Self::Target {
+        self.0.constants()
     }
 }
 
@@ -93,131 +550,4021 @@ impl fmt::Debug for Network {
     }
 }
 
-/// Provides network constants for a bitcoin-like crypto currency
-pub trait NetworkConstants {
-    /// Returns the Human-readable part for the given network
-    fn hrp(&self) -> &'static str;
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
 
-    /// Returns the prefix byte for legacy p2pk addresses
-    fn p2pk_prefix(&self) -> u8;
+impl str::FromStr for Network {
+    type Err = ParseNetworkError;
 
-    /// Returns the prefix byte for legacy p2pkh addresses
-    fn p2pkh_prefix(&self) -> u8;
+    /// Parses the `name()` of a built-in network or of a network registered with the global
+    /// [`NetworkRegistry`](struct.NetworkRegistry.html) (requires `std`)
+    fn from_str(s: &str) -> Result<Network, ParseNetworkError> {
+        Network::from_name(s).ok_or_else(|| ParseNetworkError(s.to_string()))
+    }
+}
 
-    /// Returns the prefix byte for legacy p2sh addresses
-    fn p2sh_prefix(&self) -> u8;
+/// Error returned when parsing a string that isn't the name of a built-in network or of a network
+/// registered with the global [`NetworkRegistry`](struct.NetworkRegistry.html)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNetworkError(pub String);
 
-    /// Returns the prefix bytes for encoding xpub keys
-    fn xpub_prefix(&self) -> &'static [u8; 4];
+impl fmt::Display for ParseNetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown network: '{}'", self.0)
+    }
+}
 
-    /// Returns the prefix bytes for encoding xpriv keys
-    fn xpriv_prefix(&self) -> &'static [u8; 4];
+#[cfg(feature = "std")]
+impl std::error::Error for ParseNetworkError {}
 
-    /// Returns the prefix byte for encoding private keys as WIF
-    fn wif_prefix(&self) -> u8;
+/// Error returned by `Network::try_from_magic` if no network uses the magic
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownMagic(pub u32);
 
-    /// Returns the network's magic bytes
-    fn magic(&self) -> u32;
+impl fmt::Display for UnknownMagic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown network magic: 0x{:08X}", self.0)
+    }
+}
 
-    /// Returns a string representation of the networks identity (a.k.a. name)
-    fn name(&self) -> &'static str;
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownMagic {}
 
-    /// Describes the nature of the network (production/testing)
-    fn network_type(&self) -> NetworkType;
+/// Error returned by `Network::try_from_hrp` if no network uses the bech32 human readable part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownHrp(pub String);
 
-    /// Returns parameters for the chain's consensus
-    fn chain_params(&self) -> ChainParams;
+impl fmt::Display for UnknownHrp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown network hrp: '{}'", self.0)
+    }
+}
 
-    /// Returns the hash of the genesis block
-    fn genesis_block(&self) -> sha256d::Hash;
+#[cfg(feature = "std")]
+impl std::error::Error for UnknownHrp {}
 
-    /// Creates a boxed copy of `self`
-    fn clone_boxed(&self) -> Box<NetworkConstants>;
+/// Networks are identified by their `name()`, `magic()` and `challenge_script()`, custom signets
+/// share a name but not their challenge
+impl PartialEq for Network {
+    fn eq(&self, other: &Network) -> bool {
+        self.name() == other.name()
+            && self.magic() == other.magic()
+            && self.challenge_script() == other.challenge_script()
+    }
 }
 
-/// Describes the nature of the network
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum NetworkType {
-    /// Public production network with real economic activity
-    Mainnet,
+impl Eq for Network {}
 
-    /// Public network without real economic activity, for testing purposes only
-    Testnet,
+/// Networks are ordered by their `name()`, then `magic()` and `challenge_script()`
+impl PartialOrd for Network {
+    fn partial_cmp(&self, other: &Network) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-    /// Private testnet, typically created and controlled by a single actor
-    Regtest,
+impl Ord for Network {
+    fn cmp(&self, other: &Network) -> cmp::Ordering {
+        self.name()
+            .cmp(other.name())
+            .then_with(|| self.magic().cmp(&other.magic()))
+            .then_with(|| self.challenge_script().cmp(&other.challenge_script()))
+    }
 }
 
-/// Parameters that influence chain consensus.
-#[derive(Debug, Clone)]
-pub struct ChainParams {
-    /// Time when BIP16 becomes active.
-    pub bip16_time: u32,
+impl hash::Hash for Network {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+        self.magic().hash(state);
+        self.challenge_script().hash(state)
+    }
+}
 
-    /// Block height at which BIP34 becomes active.
-    pub bip34_height: u32,
+/// The default network is the bitcoin mainnet, also for structs deriving `Default` that contain a
+/// `Network`
+impl Default for Network {
+    fn default() -> Network {
+        Network::bitcoin()
+    }
+}
 
-    /// Block height at which BIP65 becomes active.
-    pub bip65_height: u32,
+/// Networks defined outside of this crate, looked up by `Network::from_hrp`,
+/// `Network::from_magic` and `Network::from_name` after the built-in networks when registered with
+/// the [`global`](#method.global) registry
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub struct NetworkRegistry {
+    networks: Vec<Arc<dyn NetworkConstants + Send + Sync>>,
+}
 
-    /// Block height at which BIP66 becomes active.
-    pub bip66_height: u32,
+#[cfg(feature = "std")]
+impl NetworkRegistry {
+    /// Creates an empty registry
+    pub fn new() -> NetworkRegistry {
+        NetworkRegistry { networks: Vec::new() }
+    }
 
-    /// Minimum blocks including miner confirmation of the total of 2016 blocks in a retargeting period,
-    /// (nPowTargetTimespan / nPowTargetSpacing) which is also used for BIP9 deployments.
-    /// Examples: 1916 for 95%, 1512 for testchains.
-    pub rule_change_activation_threshold: u32,
+    /// Returns the process wide registry consulted by `Network::from_*`
+    pub fn global() -> &'static RwLock<NetworkRegistry> {
+        static INIT: Once = Once::new();
+        static GLOBAL: AtomicPtr<RwLock<NetworkRegistry>> = AtomicPtr::new(ptr::null_mut());
 
-    /// Number of blocks with the same set of rules.
-    pub miner_confirmation_window: u32,
+        INIT.call_once(|| {
+            let registry = Box::new(RwLock::new(NetworkRegistry::new()));
+            GLOBAL.store(Box::into_raw(registry), Ordering::Release);
+        });
+        // The pointer was set from a leaked box exactly once above and is never freed.
+        unsafe { &*GLOBAL.load(Ordering::Acquire) }
+    }
 
-    /// Proof of work limit value. It cointans the lowest possible difficulty.
-    pub pow_limit: [u64; 4],
+    /// Adds a network to the registry. Networks registered first take precedence in lookups.
+    pub fn register(&mut self, network: Box<dyn NetworkConstants + Send + Sync>) {
+        self.networks.push(Arc::from(network));
+    }
 
-    /// Expected amount of time to mine one block.
-    pub pow_target_spacing: u64,
+    /// Returns the first registered network whose bech32 human readable part is `hrp`
+    pub fn get_by_hrp(&self, hrp: &str) -> Option<Network> {
+        if hrp.is_empty() {
+            return None;
+        }
+        self.find(|n| n.hrp() == hrp)
+    }
 
-    /// Difficulty recalculation interval.
-    pub pow_target_timespan: u64,
+    /// Returns the first registered network whose P2P message start bytes are `magic`
+    pub fn get_by_magic(&self, magic: u32) -> Option<Network> {
+        self.find(|n| n.magic() == magic)
+    }
 
-    /// Determines whether minimal difficulty may be used for blocks or not.
-    pub allow_min_difficulty_blocks: bool,
+    /// Returns the first registered network whose `name()` is `name`
+    pub fn get_by_name(&self, name: &str) -> Option<Network> {
+        self.find(|n| n.name() == name)
+    }
 
-    /// Determines whether retargeting is disabled for this network or not.
-    pub no_pow_retargeting: bool,
+    /// Returns the first registered network matching `predicate`
+    pub fn find<F: Fn(&dyn NetworkConstants) -> bool>(&self, predicate: F) -> Option<Network> {
+        self.networks
+            .iter()
+            .find(|n| predicate(&***n))
+            .map(|n| Network(NetworkKind::Custom(n.clone())))
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use ::{Network};
+/// A network defined at runtime, built with [`NetworkBuilder`](struct.NetworkBuilder.html). It can
+/// be wrapped in a `Network` with `Network::from_box` or registered with a `NetworkRegistry`.
+#[derive(Debug, Clone)]
+pub struct CustomNetwork {
+    hrp: &'static str,
+    bolt11_hrp: &'static str,
+    p2pk_prefix: u8,
+    p2pkh_prefix: u8,
+    p2sh_prefix: u8,
+    xpub_prefix: &'static [u8; 4],
+    xpriv_prefix: &'static [u8; 4],
+    ypub_prefix: &'static [u8; 4],
+    ypriv_prefix: &'static [u8; 4],
+    zpub_prefix: &'static [u8; 4],
+    zpriv_prefix: &'static [u8; 4],
+    ypub_multisig_prefix: &'static [u8; 4],
+    ypriv_multisig_prefix: &'static [u8; 4],
+    zpub_multisig_prefix: &'static [u8; 4],
+    zpriv_multisig_prefix: &'static [u8; 4],
+    wif_prefix: u8,
+    magic: u32,
+    name: &'static str,
+    currency_name: &'static str,
+    short_display_name: &'static str,
+    ticker: &'static str,
+    default_datadir_name_unix: &'static str,
+    default_datadir_name_windows: &'static str,
+    network_subdir_name: Option<&'static str>,
+    network_type: NetworkType,
+    chain_params: ChainParams,
+    bip9_deployments: &'static [Bip9Deployment],
+    genesis_block: sha256d::Hash,
+    genesis_block_timestamp: u32,
+    genesis_block_nonce: u32,
+    genesis_block_bits: u32,
+    genesis_block_version: i32,
+    genesis_merkle_root: sha256d::Hash,
+    genesis_block_coinbase_value_satoshis: u64,
+    genesis_coinbase_is_spendable: bool,
+    minimum_chain_work_hex: &'static str,
+    assumed_valid_block: Option<sha256d::Hash>,
+    supports_taproot: bool,
+    halving_interval: u32,
+    slip44_coin_type: u32,
+    default_port: u16,
+    rpc_port: u16,
+    coinbase_maturity: u32,
+    max_money: u64,
+    initial_block_subsidy_satoshis: u64,
+}
 
-    fn all_networks() -> Vec<Network> {
-        vec![Network::bitcoin(), Network::bitcoin_testnet(), Network::bitcoin_regtest()]
+impl CustomNetwork {
+    /// Copies all constants of `network`
+    fn copy_of(network: &dyn NetworkConstants) -> CustomNetwork {
+        CustomNetwork {
+            hrp: network.hrp(),
+            bolt11_hrp: network.bolt11_hrp(),
+            p2pk_prefix: network.p2pk_prefix(),
+            p2pkh_prefix: network.p2pkh_prefix(),
+            p2sh_prefix: network.p2sh_prefix(),
+            xpub_prefix: network.xpub_prefix(),
+            xpriv_prefix: network.xpriv_prefix(),
+            ypub_prefix: network.ypub_prefix(),
+            ypriv_prefix: network.ypriv_prefix(),
+            zpub_prefix: network.zpub_prefix(),
+            zpriv_prefix: network.zpriv_prefix(),
+            ypub_multisig_prefix: network.ypub_multisig_prefix(),
+            ypriv_multisig_prefix: network.ypriv_multisig_prefix(),
+            zpub_multisig_prefix: network.zpub_multisig_prefix(),
+            zpriv_multisig_prefix: network.zpriv_multisig_prefix(),
+            wif_prefix: network.wif_prefix(),
+            magic: network.magic(),
+            name: network.name(),
+            currency_name: network.currency_name(),
+            short_display_name: network.short_display_name(),
+            ticker: network.ticker(),
+            default_datadir_name_unix: network.default_datadir_name_unix(),
+            default_datadir_name_windows: network.default_datadir_name_windows(),
+            network_subdir_name: network.network_subdir_name(),
+            network_type: network.network_type(),
+            chain_params: network.chain_params(),
+            bip9_deployments: network.bip9_deployments(),
+            genesis_block: network.genesis_block(),
+            genesis_block_timestamp: network.genesis_block_timestamp(),
+            genesis_block_nonce: network.genesis_block_nonce(),
+            genesis_block_bits: network.genesis_block_bits(),
+            genesis_block_version: network.genesis_block_version(),
+            genesis_merkle_root: network.genesis_merkle_root(),
+            genesis_block_coinbase_value_satoshis: network.genesis_block_coinbase_value_satoshis(),
+            genesis_coinbase_is_spendable: network.genesis_coinbase_is_spendable(),
+            minimum_chain_work_hex: network.minimum_chain_work_hex(),
+            assumed_valid_block: network.assumed_valid_block(),
+            supports_taproot: network.supports_taproot(),
+            halving_interval: network.halving_interval(),
+            slip44_coin_type: network.slip44_coin_type(),
+            default_port: network.default_port(),
+            rpc_port: network.rpc_port(),
+            coinbase_maturity: network.coinbase_maturity(),
+            max_money: network.max_money(),
+            initial_block_subsidy_satoshis: network.initial_block_subsidy_satoshis(),
+        }
     }
+}
 
-    #[test]
-    fn debug() {
-        for n in all_networks() {
-            assert!(format!("{:?}", n).contains(n.name()));
-        }
+impl NetworkConstants for CustomNetwork {
+    fn hrp(&self) -> &'static str {
+        self.hrp
     }
 
-    #[test]
-    fn dont_panic() {
-        for n in all_networks() {
-            let _ = n.hrp();
-            let _ = n.p2pk_prefix();
-            let _ = n.p2pkh_prefix();
-            let _ = n.p2sh_prefix();
-            let _ = n.xpub_prefix();
-            let _ = n.xpriv_prefix();
-            let _ = n.wif_prefix();
-            let _ = n.magic();
-            let _ = n.name();
-            let _ = n.network_type();
-            let _ = n.chain_params();
-            let _ = n.genesis_block();
-            let _ = n.clone_boxed();
+    fn bolt11_hrp(&self) -> &'static str {
+        self.bolt11_hrp
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        self.p2pk_prefix
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        self.p2pkh_prefix
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        self.p2sh_prefix
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        self.xpub_prefix
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        self.xpriv_prefix
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        self.ypub_prefix
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        self.ypriv_prefix
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        self.zpub_prefix
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        self.zpriv_prefix
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        self.ypub_multisig_prefix
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        self.ypriv_multisig_prefix
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        self.zpub_multisig_prefix
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        self.zpriv_multisig_prefix
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        self.wif_prefix
+    }
+
+    fn magic(&self) -> u32 {
+        self.magic
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn currency_name(&self) -> &'static str {
+        self.currency_name
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        self.short_display_name
+    }
+
+    fn ticker(&self) -> &'static str {
+        self.ticker
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        self.default_datadir_name_unix
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        self.default_datadir_name_windows
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        self.network_subdir_name
+    }
+
+    fn network_type(&self) -> NetworkType {
+        self.network_type
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        self.chain_params.clone()
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        self.bip9_deployments
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        self.genesis_block
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        self.genesis_block_timestamp
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        self.genesis_block_nonce
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        self.genesis_block_bits
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        self.genesis_block_version
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        self.genesis_merkle_root
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        self.genesis_block_coinbase_value_satoshis
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        self.genesis_coinbase_is_spendable
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        self.minimum_chain_work_hex
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        self.assumed_valid_block
+    }
+
+    fn supports_taproot(&self) -> bool {
+        self.supports_taproot
+    }
+
+    fn halving_interval(&self) -> u32 {
+        self.halving_interval
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        self.slip44_coin_type
+    }
+
+    fn default_port(&self) -> u16 {
+        self.default_port
+    }
+
+    fn rpc_port(&self) -> u16 {
+        self.rpc_port
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        self.coinbase_maturity
+    }
+
+    fn max_money(&self) -> u64 {
+        self.max_money
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        self.initial_block_subsidy_satoshis
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Box::new(self.clone())
+    }
+}
+
+/// Builds a [`CustomNetwork`](struct.CustomNetwork.html). The name, HRP and magic have to be set,
+/// all other constants default to the ones of the bitcoin mainnet.
+#[derive(Debug, Clone)]
+pub struct NetworkBuilder {
+    name: Option<&'static str>,
+    hrp: Option<&'static str>,
+    magic: Option<u32>,
+    network: CustomNetwork,
+}
+
+impl NetworkBuilder {
+    /// Creates a builder without name, HRP and magic
+    pub fn new() -> NetworkBuilder {
+        NetworkBuilder {
+            name: None,
+            hrp: None,
+            magic: None,
+            network: CustomNetwork::copy_of(&networks::BITCOIN),
+        }
+    }
+
+    /// Sets the value returned by `name()`, required
+    pub fn name(mut self, name: &'static str) -> NetworkBuilder {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the value returned by `hrp()`, required
+    pub fn hrp(mut self, hrp: &'static str) -> NetworkBuilder {
+        self.hrp = Some(hrp);
+        self
+    }
+
+    /// Sets the value returned by `bolt11_hrp()`
+    pub fn bolt11_hrp(mut self, bolt11_hrp: &'static str) -> NetworkBuilder {
+        self.network.bolt11_hrp = bolt11_hrp;
+        self
+    }
+
+    /// Sets the value returned by `magic()`, required
+    pub fn magic(mut self, magic: u32) -> NetworkBuilder {
+        self.magic = Some(magic);
+        self
+    }
+
+    /// Sets the value returned by `p2pk_prefix()`
+    pub fn p2pk_prefix(mut self, p2pk_prefix: u8) -> NetworkBuilder {
+        self.network.p2pk_prefix = p2pk_prefix;
+        self
+    }
+
+    /// Sets the value returned by `p2pkh_prefix()`
+    pub fn p2pkh_prefix(mut self, p2pkh_prefix: u8) -> NetworkBuilder {
+        self.network.p2pkh_prefix = p2pkh_prefix;
+        self
+    }
+
+    /// Sets the value returned by `p2sh_prefix()`
+    pub fn p2sh_prefix(mut self, p2sh_prefix: u8) -> NetworkBuilder {
+        self.network.p2sh_prefix = p2sh_prefix;
+        self
+    }
+
+    /// Sets the value returned by `xpub_prefix()`
+    pub fn xpub_prefix(mut self, xpub_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.xpub_prefix = xpub_prefix;
+        self
+    }
+
+    /// Sets the value returned by `xpriv_prefix()`
+    pub fn xpriv_prefix(mut self, xpriv_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.xpriv_prefix = xpriv_prefix;
+        self
+    }
+
+    /// Sets the value returned by `ypub_prefix()`
+    pub fn ypub_prefix(mut self, ypub_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.ypub_prefix = ypub_prefix;
+        self
+    }
+
+    /// Sets the value returned by `ypriv_prefix()`
+    pub fn ypriv_prefix(mut self, ypriv_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.ypriv_prefix = ypriv_prefix;
+        self
+    }
+
+    /// Sets the value returned by `zpub_prefix()`
+    pub fn zpub_prefix(mut self, zpub_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.zpub_prefix = zpub_prefix;
+        self
+    }
+
+    /// Sets the value returned by `zpriv_prefix()`
+    pub fn zpriv_prefix(mut self, zpriv_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.zpriv_prefix = zpriv_prefix;
+        self
+    }
+
+    /// Sets the value returned by `ypub_multisig_prefix()`
+    pub fn ypub_multisig_prefix(mut self, ypub_multisig_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.ypub_multisig_prefix = ypub_multisig_prefix;
+        self
+    }
+
+    /// Sets the value returned by `ypriv_multisig_prefix()`
+    pub fn ypriv_multisig_prefix(mut self, ypriv_multisig_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.ypriv_multisig_prefix = ypriv_multisig_prefix;
+        self
+    }
+
+    /// Sets the value returned by `zpub_multisig_prefix()`
+    pub fn zpub_multisig_prefix(mut self, zpub_multisig_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.zpub_multisig_prefix = zpub_multisig_prefix;
+        self
+    }
+
+    /// Sets the value returned by `zpriv_multisig_prefix()`
+    pub fn zpriv_multisig_prefix(mut self, zpriv_multisig_prefix: &'static [u8; 4]) -> NetworkBuilder {
+        self.network.zpriv_multisig_prefix = zpriv_multisig_prefix;
+        self
+    }
+
+    /// Sets the value returned by `wif_prefix()`
+    pub fn wif_prefix(mut self, wif_prefix: u8) -> NetworkBuilder {
+        self.network.wif_prefix = wif_prefix;
+        self
+    }
+
+    /// Sets the value returned by `currency_name()`
+    pub fn currency_name(mut self, currency_name: &'static str) -> NetworkBuilder {
+        self.network.currency_name = currency_name;
+        self
+    }
+
+    /// Sets the value returned by `short_display_name()`
+    pub fn short_display_name(mut self, short_display_name: &'static str) -> NetworkBuilder {
+        self.network.short_display_name = short_display_name;
+        self
+    }
+
+    /// Sets the value returned by `ticker()`
+    pub fn ticker(mut self, ticker: &'static str) -> NetworkBuilder {
+        self.network.ticker = ticker;
+        self
+    }
+
+    /// Sets the value returned by `default_datadir_name_unix()`
+    pub fn default_datadir_name_unix(mut self, default_datadir_name_unix: &'static str) -> NetworkBuilder {
+        self.network.default_datadir_name_unix = default_datadir_name_unix;
+        self
+    }
+
+    /// Sets the value returned by `default_datadir_name_windows()`
+    pub fn default_datadir_name_windows(mut self, default_datadir_name_windows: &'static str) -> NetworkBuilder {
+        self.network.default_datadir_name_windows = default_datadir_name_windows;
+        self
+    }
+
+    /// Sets the value returned by `network_subdir_name()`
+    pub fn network_subdir_name(mut self, network_subdir_name: Option<&'static str>) -> NetworkBuilder {
+        self.network.network_subdir_name = network_subdir_name;
+        self
+    }
+
+    /// Sets the value returned by `network_type()`
+    pub fn network_type(mut self, network_type: NetworkType) -> NetworkBuilder {
+        self.network.network_type = network_type;
+        self
+    }
+
+    /// Sets the value returned by `chain_params()`
+    pub fn chain_params(mut self, chain_params: ChainParams) -> NetworkBuilder {
+        self.network.chain_params = chain_params;
+        self
+    }
+
+    /// Sets the value returned by `bip9_deployments()`
+    pub fn bip9_deployments(
+        mut self,
+        bip9_deployments: &'static [Bip9Deployment],
+    ) -> NetworkBuilder {
+        self.network.bip9_deployments = bip9_deployments;
+        self
+    }
+
+    /// Sets the value returned by `genesis_block()`
+    pub fn genesis_block(mut self, genesis_block: sha256d::Hash) -> NetworkBuilder {
+        self.network.genesis_block = genesis_block;
+        self
+    }
+
+    /// Sets the value returned by `genesis_block_timestamp()`
+    pub fn genesis_block_timestamp(mut self, genesis_block_timestamp: u32) -> NetworkBuilder {
+        self.network.genesis_block_timestamp = genesis_block_timestamp;
+        self
+    }
+
+    /// Sets the value returned by `genesis_block_nonce()`
+    pub fn genesis_block_nonce(mut self, genesis_block_nonce: u32) -> NetworkBuilder {
+        self.network.genesis_block_nonce = genesis_block_nonce;
+        self
+    }
+
+    /// Sets the value returned by `genesis_block_bits()`
+    pub fn genesis_block_bits(mut self, genesis_block_bits: u32) -> NetworkBuilder {
+        self.network.genesis_block_bits = genesis_block_bits;
+        self
+    }
+
+    /// Sets the value returned by `genesis_block_version()`
+    pub fn genesis_block_version(mut self, genesis_block_version: i32) -> NetworkBuilder {
+        self.network.genesis_block_version = genesis_block_version;
+        self
+    }
+
+    /// Sets the value returned by `genesis_merkle_root()`
+    pub fn genesis_merkle_root(mut self, genesis_merkle_root: sha256d::Hash) -> NetworkBuilder {
+        self.network.genesis_merkle_root = genesis_merkle_root;
+        self
+    }
+
+    /// Sets the value returned by `genesis_block_coinbase_value_satoshis()`
+    pub fn genesis_block_coinbase_value_satoshis(mut self, genesis_block_coinbase_value_satoshis: u64) -> NetworkBuilder {
+        self.network.genesis_block_coinbase_value_satoshis = genesis_block_coinbase_value_satoshis;
+        self
+    }
+
+    /// Sets the value returned by `genesis_coinbase_is_spendable()`
+    pub fn genesis_coinbase_is_spendable(mut self, genesis_coinbase_is_spendable: bool) -> NetworkBuilder {
+        self.network.genesis_coinbase_is_spendable = genesis_coinbase_is_spendable;
+        self
+    }
+
+    /// Sets the value returned by `minimum_chain_work_hex()`
+    pub fn minimum_chain_work_hex(mut self, minimum_chain_work_hex: &'static str) -> NetworkBuilder {
+        self.network.minimum_chain_work_hex = minimum_chain_work_hex;
+        self
+    }
+
+    /// Sets the value returned by `assumed_valid_block()`
+    pub fn assumed_valid_block(
+        mut self,
+        assumed_valid_block: Option<sha256d::Hash>,
+    ) -> NetworkBuilder {
+        self.network.assumed_valid_block = assumed_valid_block;
+        self
+    }
+
+    /// Sets the value returned by `supports_taproot()`
+    pub fn supports_taproot(mut self, supports_taproot: bool) -> NetworkBuilder {
+        self.network.supports_taproot = supports_taproot;
+        self
+    }
+
+    /// Sets the value returned by `halving_interval()`
+    pub fn halving_interval(mut self, halving_interval: u32) -> NetworkBuilder {
+        self.network.halving_interval = halving_interval;
+        self
+    }
+
+    /// Sets the value returned by `slip44_coin_type()`
+    pub fn slip44_coin_type(mut self, slip44_coin_type: u32) -> NetworkBuilder {
+        self.network.slip44_coin_type = slip44_coin_type;
+        self
+    }
+
+    /// Sets the value returned by `default_port()`
+    pub fn default_port(mut self, default_port: u16) -> NetworkBuilder {
+        self.network.default_port = default_port;
+        self
+    }
+
+    /// Sets the value returned by `rpc_port()`
+    pub fn rpc_port(mut self, rpc_port: u16) -> NetworkBuilder {
+        self.network.rpc_port = rpc_port;
+        self
+    }
+
+    /// Sets the value returned by `coinbase_maturity()`
+    pub fn coinbase_maturity(mut self, coinbase_maturity: u32) -> NetworkBuilder {
+        self.network.coinbase_maturity = coinbase_maturity;
+        self
+    }
+
+    /// Sets the value returned by `max_money()`
+    pub fn max_money(mut self, max_money: u64) -> NetworkBuilder {
+        self.network.max_money = max_money;
+        self
+    }
+
+    /// Sets the value returned by `initial_block_subsidy_satoshis()`
+    pub fn initial_block_subsidy_satoshis(mut self, initial_block_subsidy_satoshis: u64) -> NetworkBuilder {
+        self.network.initial_block_subsidy_satoshis = initial_block_subsidy_satoshis;
+        self
+    }
+
+    /// Builds the network, failing if the name, HRP or magic wasn't set
+    pub fn build(self) -> Result<CustomNetwork, NetworkBuildError> {
+        let mut network = self.network;
+        network.name = self.name.ok_or(NetworkBuildError::MissingName)?;
+        network.hrp = self.hrp.ok_or(NetworkBuildError::MissingHrp)?;
+        network.magic = self.magic.ok_or(NetworkBuildError::MissingMagic)?;
+        if network.minimum_chain_work_bytes().is_none() {
+            return Err(NetworkBuildError::InvalidMinimumChainWork);
+        }
+        Ok(network)
+    }
+}
+
+impl Default for NetworkBuilder {
+    fn default() -> NetworkBuilder {
+        NetworkBuilder::new()
+    }
+}
+
+/// Error returned by `NetworkBuilder::build` when a required constant wasn't set or a constant is
+/// invalid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkBuildError {
+    /// The name wasn't set
+    MissingName,
+    /// The bech32 human readable part wasn't set
+    MissingHrp,
+    /// The P2P message start bytes weren't set
+    MissingMagic,
+    /// The minimum chain work isn't a hex number of at most 64 digits
+    InvalidMinimumChainWork,
+}
+
+impl fmt::Display for NetworkBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let missing = match *self {
+            NetworkBuildError::MissingName => "name",
+            NetworkBuildError::MissingHrp => "hrp",
+            NetworkBuildError::MissingMagic => "magic",
+            NetworkBuildError::InvalidMinimumChainWork => {
+                return f.write_str("minimum chain work is not a hex number of at most 64 digits");
+            }
+        };
+        write!(f, "network {} not set", missing)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NetworkBuildError {}
+
+/// Provides network constants for a bitcoin-like crypto currency
+pub trait NetworkConstants {
+    /// Returns the Human-readable part for the given network
+    fn hrp(&self) -> &'static str;
+
+    /// Returns the human readable prefix of BOLT11 lightning invoices, empty if the network has no
+    /// lightning deployment
+    fn bolt11_hrp(&self) -> &'static str;
+
+    /// Returns the prefix byte for legacy p2pk addresses
+    fn p2pk_prefix(&self) -> u8;
+
+    /// Returns the prefix byte for legacy p2pkh addresses
+    fn p2pkh_prefix(&self) -> u8;
+
+    /// Returns the prefix byte for legacy p2sh addresses
+    fn p2sh_prefix(&self) -> u8;
+
+    /// Returns the prefix bytes for encoding xpub keys
+    fn xpub_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix bytes for encoding xpriv keys
+    fn xpriv_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix bytes for encoding ypub keys (BIP49, P2WPKH nested in P2SH, SLIP-0132)
+    fn ypub_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix bytes for encoding ypriv keys (BIP49, P2WPKH nested in P2SH, SLIP-0132)
+    fn ypriv_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix bytes for encoding zpub keys (BIP84, native P2WPKH, SLIP-0132)
+    fn zpub_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix bytes for encoding zpriv keys (BIP84, native P2WPKH, SLIP-0132)
+    fn zpriv_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix bytes for encoding Ypub keys (multisig P2WSH nested in P2SH, SLIP-0132)
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix bytes for encoding Ypriv keys (multisig P2WSH nested in P2SH, SLIP-0132)
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix bytes for encoding Zpub keys (multisig native P2WSH, SLIP-0132)
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix bytes for encoding Zpriv keys (multisig native P2WSH, SLIP-0132)
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4];
+
+    /// Returns the prefix byte for encoding private keys as WIF
+    fn wif_prefix(&self) -> u8;
+
+    /// Returns the network's magic bytes
+    fn magic(&self) -> u32;
+
+    /// Returns a string representation of the networks identity (a.k.a. name)
+    fn name(&self) -> &'static str;
+
+    /// Returns the name of the currency the network belongs to, shared by all networks of that
+    /// currency (e.g. "bitcoin" for both mainnet and testnet)
+    fn currency_name(&self) -> &'static str;
+
+    /// Returns a human-friendly name of the network, e.g. "Bitcoin Testnet"
+    fn short_display_name(&self) -> &'static str;
+
+    /// Returns the ticker symbol of the currency, e.g. "BTC"
+    fn ticker(&self) -> &'static str;
+
+    /// Returns the name of the reference node's data directory in the user's home directory on
+    /// unix-like systems, e.g. ".bitcoin"
+    fn default_datadir_name_unix(&self) -> &'static str;
+
+    /// Returns the name of the reference node's data directory in `%APPDATA%` on Windows, e.g.
+    /// "Bitcoin"
+    fn default_datadir_name_windows(&self) -> &'static str;
+
+    /// Returns the name of the network specific subdirectory inside the data directory, `None` if
+    /// the network's data is stored in the data directory itself
+    fn network_subdir_name(&self) -> Option<&'static str>;
+
+    /// Describes the nature of the network (production/testing)
+    fn network_type(&self) -> NetworkType;
+
+    /// Returns parameters for the chain's consensus
+    fn chain_params(&self) -> ChainParams;
+
+    /// Returns the BIP9 version bits deployments of the chain's historical soft forks, empty if
+    /// they were not deployed via BIP9 or aren't tracked for the network
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment];
+
+    /// Returns the hash of the genesis block
+    fn genesis_block(&self) -> sha256d::Hash;
+
+    /// Returns the timestamp of the genesis block header, in seconds since the unix epoch
+    fn genesis_block_timestamp(&self) -> u32;
+
+    /// Returns the nonce of the genesis block header
+    fn genesis_block_nonce(&self) -> u32;
+
+    /// Returns the difficulty target of the genesis block header in compact (`nBits`) form
+    fn genesis_block_bits(&self) -> u32;
+
+    /// Returns the version field of the genesis block header
+    fn genesis_block_version(&self) -> i32;
+
+    /// Returns the merkle root of the genesis block, i.e. the hash of its coinbase transaction
+    fn genesis_merkle_root(&self) -> sha256d::Hash;
+
+    /// Returns the value of the genesis block's coinbase output in the base unit
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64;
+
+    /// Returns whether the genesis block's coinbase output can be spent. Bitcoin's original client
+    /// never added it to the UTXO set, making it unspendable.
+    fn genesis_coinbase_is_spendable(&self) -> bool;
+
+    /// Returns the minimum amount of cumulative chain work a chain must have to be considered by the
+    /// reference node (`nMinimumChainWork`), as a 64 characters big endian hex string. The values
+    /// are taken from Bitcoin Core 26.0, or 28.0 for testnet4 which it introduced.
+    fn minimum_chain_work_hex(&self) -> &'static str;
+
+    /// Returns the block up to which the reference node skips script verification by default
+    /// (`-assumevalid`), `None` if there is none. The value is pinned when this crate is released
+    /// and gets out of date, it only has to be an ancestor of the best chain.
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash>;
+
+    /// Returns whether the network's consensus rules include Taproot (BIP341)
+    fn supports_taproot(&self) -> bool;
+
+    /// Returns the number of blocks after which the block subsidy halves, also known as blocks per
+    /// halving era. Networks without halvings return `u32::MAX`.
+    fn halving_interval(&self) -> u32;
+
+    /// Returns the coin type registered in SLIP-0044, used in BIP44 derivation paths
+    /// `m/44'/coin_type'/account'`. All test networks use coin type 1. Networks which aren't
+    /// registered return `u32::MAX`.
+    fn slip44_coin_type(&self) -> u32;
+
+    /// Returns the reference node's default port for P2P connections
+    fn default_port(&self) -> u16;
+
+    /// Returns the reference node's default port for JSON-RPC connections
+    fn rpc_port(&self) -> u16;
+
+    /// Returns the number of confirmations a coinbase output needs before it can be spent. Spending
+    /// it earlier makes the block invalid (`COINBASE_MATURITY` consensus rule).
+    fn coinbase_maturity(&self) -> u32;
+
+    /// Returns the supply cap in the base unit, no transaction output or sum of outputs may exceed
+    /// it. Networks without a known cap return `u64::MAX`.
+    fn max_money(&self) -> u64;
+
+    /// Returns the block subsidy in the base unit before the first halving, see
+    /// [`halving_interval`](#tymethod.halving_interval)
+    fn initial_block_subsidy_satoshis(&self) -> u64;
+
+    /// Creates a boxed copy of `self`
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants>;
+
+    /// Returns the number of base units (satoshis for bitcoin) per coin, a power of ten
+    fn satoshis_per_bitcoin(&self) -> u64 {
+        100_000_000
+    }
+
+    /// Returns the value an `OP_RETURN` output must have to be standard
+    fn op_return_output_value(&self) -> u64 {
+        0
+    }
+
+    /// Returns the maximum size of the data pushed in a standard `OP_RETURN` output
+    fn max_op_return_size_bytes(&self) -> usize {
+        80
+    }
+
+    /// Checks if an `OP_RETURN` output with the given value and script size is standard. The
+    /// script may be two bytes larger than the data for the `OP_RETURN` and push opcodes.
+    fn is_valid_op_return_output(&self, value_satoshis: u64, script_size: usize) -> bool {
+        value_satoshis == self.op_return_output_value()
+            && script_size <= self.max_op_return_size_bytes() + 2
+    }
+
+    /// Returns the maximum number of public keys in a standard bare multisig (P2MS) output.
+    ///
+    /// This is a relay policy limit. The consensus limit of `OP_CHECKMULTISIG` is 20 keys.
+    fn p2ms_max_keys_standard_output(&self) -> u8 {
+        3
+    }
+
+    /// Returns the maximum number of signature operations in a standard P2SH redeem script, which
+    /// limits multisig redeem scripts to 15 keys.
+    ///
+    /// This is a relay policy limit. The consensus limit of `OP_CHECKMULTISIG` is 20 keys.
+    fn p2sh_max_sigops_standard(&self) -> u8 {
+        15
+    }
+
+    /// Checks if an `m`-of-`n` bare multisig (P2MS) output is standard (relay policy)
+    fn is_standard_p2ms_output(&self, m: u8, n: u8) -> bool {
+        m <= n && n <= self.p2ms_max_keys_standard_output() && m >= 1
+    }
+
+    /// Returns the `nSequence` value marking an input as final, which disables replace-by-fee,
+    /// `nLockTime` and relative lock times
+    fn sequence_final(&self) -> u32 {
+        0xFFFFFFFF
+    }
+
+    /// Returns the highest `nSequence` value that enables `nLockTime` but does not signal
+    /// replace-by-fee
+    fn sequence_enable_locktime(&self) -> u32 {
+        0xFFFFFFFE
+    }
+
+    /// Returns the highest `nSequence` value signaling BIP125 replace-by-fee
+    fn sequence_bip125_rbf_max(&self) -> u32 {
+        0xFFFFFFFD
+    }
+
+    /// Returns the highest `nSequence` value signaling opt-in replace-by-fee, same as
+    /// [`sequence_bip125_rbf_max`](#method.sequence_bip125_rbf_max)
+    fn sequence_opt_in_rbf_max(&self) -> u32 {
+        0xFFFFFFFD
+    }
+
+    /// Checks if an input's `nSequence` value signals BIP125 replace-by-fee
+    fn is_rbf_signal(&self, sequence: u32) -> bool {
+        sequence <= self.sequence_bip125_rbf_max()
+    }
+
+    /// Returns the highest `nSequence` value signaling BIP125 opt-in replace-by-fee, same as
+    /// [`sequence_bip125_rbf_max`](#method.sequence_bip125_rbf_max)
+    fn bip125_opt_in_rbf_max_sequence(&self) -> u32 {
+        self.sequence_bip125_rbf_max()
+    }
+
+    /// Returns the fee rate in sat/kvB a BIP125 replacement has to pay for its own size on top of
+    /// the fees of the replaced transactions (incremental relay fee)
+    fn bip125_replacement_fee_increment_sat_per_kvb(&self) -> u64 {
+        1000
+    }
+
+    /// Checks if an input's `nSequence` value signals BIP125 opt-in replace-by-fee
+    fn bip125_is_opt_in_rbf(&self, sequence: u32) -> bool {
+        sequence <= self.bip125_opt_in_rbf_max_sequence()
+    }
+
+    /// Returns the minimum fee a BIP125 replacement of size `replacement_size_kvb` has to pay when
+    /// replacing transactions paying `original_fee` in total
+    fn bip125_replacement_min_fee_bump_satoshis(&self, original_fee: u64, replacement_size_kvb: u64) -> u64 {
+        original_fee.saturating_add(
+            self.bip125_replacement_fee_increment_sat_per_kvb().saturating_mul(replacement_size_kvb),
+        )
+    }
+
+    /// Returns the number of blocks per halving era, an alias of
+    /// [`halving_interval`](#tymethod.halving_interval)
+    fn blocks_per_era(&self) -> u32 {
+        self.halving_interval()
+    }
+
+    /// Returns the halving era the block at `height` belongs to, starting at 0. A
+    /// `halving_interval()` of 0 is treated as never halving, every block is in era 0.
+    fn era_at_height(&self, height: u32) -> u32 {
+        height.checked_div(self.halving_interval()).unwrap_or(0)
+    }
+
+    /// Returns the height of the first block of the given halving era
+    fn first_height_of_era(&self, era: u32) -> u32 {
+        era.saturating_mul(self.halving_interval())
+    }
+
+    /// Returns the height of the last block of the given halving era
+    fn last_height_of_era(&self, era: u32) -> u32 {
+        self.first_height_of_era(era.saturating_add(1)).saturating_sub(1)
+    }
+
+    /// Returns the block subsidy of the block at `height` in the base unit, halving the initial
+    /// subsidy every `halving_interval()` blocks until it reaches 0. Networks with other emission
+    /// schedules get the value of this model. A `halving_interval()` of 0 or `u32::MAX` means the
+    /// subsidy never halves.
+    fn block_subsidy_at_height(&self, height: u32) -> u64 {
+        if self.halving_interval() == 0 || self.halving_interval() == u32::MAX {
+            return self.initial_block_subsidy_satoshis();
+        }
+        self.initial_block_subsidy_satoshis().checked_shr(self.era_at_height(height)).unwrap_or(0)
+    }
+
+    /// Returns the maximum number of entries in an `addr` message
+    fn max_addr_message_entries(&self) -> usize {
+        1_000
+    }
+
+    /// Returns the maximum number of entries in an `addrv2` message (BIP155)
+    fn max_addrv2_message_entries(&self) -> usize {
+        1_000
+    }
+
+    /// Returns the reference node's default number of outbound connections (full relay and block
+    /// relay only)
+    fn max_outbound_connections_default(&self) -> u32 {
+        10
+    }
+
+    /// Returns the reference node's default maximum number of connections (`-maxconnections`)
+    fn max_inbound_connections_default(&self) -> u32 {
+        125
+    }
+
+    /// Returns the number of short lived feeler connections the reference node keeps open
+    fn max_feeler_connections(&self) -> u32 {
+        1
+    }
+
+    /// Returns the time in seconds a peer has to deliver a block requested with `getdata`
+    fn getdata_block_timeout_seconds(&self) -> u32 {
+        20 * 60
+    }
+
+    /// Returns the time in seconds a peer has to deliver a transaction requested with `getdata`
+    fn getdata_tx_timeout_seconds(&self) -> u32 {
+        2 * 60
+    }
+
+    /// Returns the interval in seconds between `ping` messages sent to a peer
+    fn ping_interval_seconds(&self) -> u32 {
+        2 * 60
+    }
+
+    /// Returns the time in seconds a peer has to answer a `ping` with a `pong`
+    fn ping_timeout_seconds(&self) -> u32 {
+        20
+    }
+
+    /// Returns the time in seconds a new connection has to complete the version handshake
+    fn connection_timeout_seconds(&self) -> u32 {
+        60
+    }
+
+    /// Returns [`minimum_chain_work_hex`](#tymethod.minimum_chain_work_hex) as big endian bytes.
+    /// An `0x` prefix and fewer than 64 hex digits are accepted, e.g. `"0x00"`. Returns `None` if
+    /// the string isn't a hex number of at most 64 digits.
+    fn minimum_chain_work_bytes(&self) -> Option<[u8; 32]> {
+        let hex = self.minimum_chain_work_hex();
+        let hex = if hex.starts_with("0x") { &hex[2..] } else { hex };
+        if hex.is_empty() || hex.len() > 64 {
+            return None;
+        }
+        let mut work = [0u8; 32];
+        for (i, digit) in hex.bytes().rev().enumerate() {
+            let value = (digit as char).to_digit(16)? as u8;
+            work[31 - i / 2] |= value << (4 * (i % 2));
+        }
+        Some(work)
+    }
+
+    /// Returns the version byte prepended to BIP47 payment codes before base58check encoding
+    fn bip47_version_byte(&self) -> u8 {
+        0x47
+    }
+
+    /// Returns the length of a serialized BIP47 payment code
+    fn bip47_payment_code_bytes(&self) -> usize {
+        80
+    }
+
+    /// Returns the bit mask of the BIP47 features byte signaling Bitmessage notification
+    fn bip47_feature_bit_bitmask(&self) -> u8 {
+        0x01
+    }
+
+    /// Checks if the big endian x coordinate of a BIP47 payment code's public key is an element of
+    /// the secp256k1 field
+    fn bip47_x_coordinate_is_valid(&self, x: &[u8; 32]) -> bool {
+        static FIELD_SIZE: [u8; 32] = [
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0xFF, 0xFF, 0xFC, 0x2F,
+        ];
+        x < &FIELD_SIZE
+    }
+
+    /// Returns the number of confirmations after which wallets commonly consider a transaction final
+    fn finality_confirmations(&self) -> u32 {
+        6
+    }
+
+    /// Returns the number of confirmations commonly considered sufficient for small amounts
+    fn small_amount_confirmations(&self) -> u32 {
+        1
+    }
+
+    /// Checks if a transaction with the given number of confirmations is considered final
+    fn is_tx_final(&self, confirmations: u32) -> bool {
+        confirmations >= self.finality_confirmations()
+    }
+
+    /// Returns the number of confirmations that take as long on average as 6 confirmations on
+    /// bitcoin (one hour), rounded up. Saturates at `u32::MAX` if `pow_target_spacing` is zero.
+    fn confirmations_equivalent_to_bitcoin_6(&self) -> u32 {
+        const BITCOIN_6_SECONDS: u64 = 6 * 10 * 60;
+        let spacing = self.chain_params().pow_target_spacing;
+        match BITCOIN_6_SECONDS.checked_div(spacing) {
+            Some(blocks) => (blocks + u64::from(BITCOIN_6_SECONDS % spacing != 0)) as u32,
+            None => u32::MAX,
+        }
+    }
+
+    /// Returns whether reusing addresses is discouraged on this network for privacy reasons
+    fn discourages_address_reuse(&self) -> bool {
+        true
+    }
+
+    /// Returns a privacy warning wallets can show when an address is reused, `None` if address
+    /// reuse isn't discouraged on this network
+    fn address_reuse_warning_message(&self) -> Option<&'static str> {
+        if self.discourages_address_reuse() {
+            Some("Reusing addresses harms your privacy and the privacy of others, use a new address for every payment.")
+        } else {
+            None
+        }
+    }
+
+    /// Returns the output descriptor types (BIP380 and following) valid on this network
+    fn supported_descriptor_types(&self) -> &'static [DescriptorType] {
+        static TYPES: [DescriptorType; 11] = [
+            DescriptorType::Pk,
+            DescriptorType::Pkh,
+            DescriptorType::Wpkh,
+            DescriptorType::Sh,
+            DescriptorType::Wsh,
+            DescriptorType::Tr,
+            DescriptorType::Multi,
+            DescriptorType::Sortedmulti,
+            DescriptorType::Combo,
+            DescriptorType::Addr,
+            DescriptorType::Raw,
+        ];
+        &TYPES
+    }
+
+    /// Checks if the given output descriptor type is valid on this network
+    fn descriptor_type_is_supported(&self, dt: DescriptorType) -> bool {
+        self.supported_descriptor_types().contains(&dt)
+    }
+
+    /// Returns whether MuSig2 (BIP327) multi-signatures can be used, which requires Taproot outputs
+    fn musig2_supported(&self) -> bool {
+        self.supports_taproot()
+    }
+
+    /// Returns the size of a signer's MuSig2 public nonce, two 33 byte points
+    fn musig2_nonce_size_bytes(&self) -> usize {
+        66
+    }
+
+    /// Returns the size of a MuSig2 partial signature, a scalar
+    fn musig2_partial_signature_size_bytes(&self) -> usize {
+        32
+    }
+
+    /// Returns the maximum number of hash functions (`nHashFuncs`) of a BIP37 bloom filter
+    fn bloom_filter_max_hash_funcs(&self) -> u32 {
+        50
+    }
+
+    /// Returns the BIP37 `nFlags` value `BLOOM_UPDATE_NONE`, matched outpoints aren't added to the
+    /// filter
+    fn bloom_filter_nflags_none(&self) -> u8 {
+        0
+    }
+
+    /// Returns the BIP37 `nFlags` value `BLOOM_UPDATE_ALL`, outpoints of all matched outputs are
+    /// added to the filter
+    fn bloom_filter_nflags_all(&self) -> u8 {
+        1
+    }
+
+    /// Returns the BIP37 `nFlags` value `BLOOM_UPDATE_P2PUBKEY_ONLY`, only outpoints of matched
+    /// pay to public key and multisig outputs are added to the filter
+    fn bloom_filter_nflags_p2pubkey_only(&self) -> u8 {
+        2
+    }
+
+    /// Returns the maximum size of a BIP37 bloom filter (`MAX_BLOOM_FILTER_SIZE`)
+    fn bloom_filter_max_size_bytes(&self) -> usize {
+        36_000
+    }
+
+    /// Returns the largest value a CompactSize integer encodes in a single byte
+    fn compact_size_1_byte_max(&self) -> u64 {
+        0xFC
+    }
+
+    /// Returns the marker byte of a CompactSize followed by a 2 byte integer
+    fn compact_size_3_byte_marker(&self) -> u8 {
+        0xFD
+    }
+
+    /// Returns the marker byte of a CompactSize followed by a 4 byte integer
+    fn compact_size_5_byte_marker(&self) -> u8 {
+        0xFE
+    }
+
+    /// Returns the marker byte of a CompactSize followed by an 8 byte integer
+    fn compact_size_9_byte_marker(&self) -> u8 {
+        0xFF
+    }
+
+    /// Returns the number of bytes `value` takes up when encoded as CompactSize
+    fn compact_size_encoded_length(&self, value: u64) -> usize {
+        if value <= self.compact_size_1_byte_max() {
+            1
+        } else if value <= 0xFFFF {
+            3
+        } else if value <= 0xFFFFFFFF {
+            5
+        } else {
+            9
+        }
+    }
+
+    /// Returns a precomputed SHA256 midstate of legacy signature hashes of version 1 transactions.
+    /// The absorbed prefix depends on the transaction, so there is none.
+    fn precomputed_sha256_midstate_for_version_1_inputs(&self) -> Option<[u8; 32]> {
+        None
+    }
+
+    /// Returns the SHA256 state after absorbing `SHA256("TapSighash") || SHA256("TapSighash")`,
+    /// the prefix of BIP341 tagged signature hashes
+    fn taproot_sighash_sha256_initial_state(&self) -> [u32; 8] {
+        [
+            0xF504A425, 0xD7F8783B, 0x1363868A, 0xE3E55658,
+            0x6EEE945D, 0xBC7888DD, 0x02A6E2C3, 0x1873FE9F,
+        ]
+    }
+
+    /// Returns the start of the coinbase output script committing to the witness data (BIP141):
+    /// `OP_RETURN`, a push of 36 bytes and the commitment header `0xaa21a9ed`
+    fn segwit_commitment_header(&self) -> &'static [u8; 6] {
+        static HEADER: [u8; 6] = [0x6a, 0x24, 0xaa, 0x21, 0xa9, 0xed];
+        &HEADER
+    }
+
+    /// Returns the size of the coinbase output script committing to the witness data, the header
+    /// followed by the 32 byte commitment hash
+    fn segwit_commitment_total_size(&self) -> usize {
+        38
+    }
+
+    /// Returns the witness reserved value of the coinbase input (BIP141)
+    fn segwit_witness_reserved_value(&self) -> &'static [u8; 32] {
+        static VALUE: [u8; 32] = [0; 32];
+        &VALUE
+    }
+
+    /// Checks if the given coinbase output script is a witness commitment
+    fn segwit_commitment_is_present(&self, script: &[u8]) -> bool {
+        script.len() >= self.segwit_commitment_total_size()
+            && script.starts_with(self.segwit_commitment_header())
+    }
+
+    /// Returns whether BIP158 compact block filters are defined for this network
+    fn bip158_filter_supported(&self) -> bool {
+        true
+    }
+
+    /// Returns the BIP158 filter type of basic filters
+    fn bip158_filter_type_basic(&self) -> u8 {
+        0
+    }
+
+    /// Returns the BIP158 Golomb-Rice coding false positive rate parameter `M`
+    fn bip158_filter_parameter_m(&self) -> u64 {
+        784931
+    }
+
+    /// Returns the BIP158 Golomb-Rice coding bit parameter `P`
+    fn bip158_filter_parameter_p(&self) -> u8 {
+        19
+    }
+
+    /// Returns the constant prefix of the BIP158 SipHash key. It is empty since the key is derived
+    /// from the block hash only.
+    fn bip158_siphash_key_prefix(&self) -> &'static [u8; 0] {
+        static PREFIX: [u8; 0] = [];
+        &PREFIX
+    }
+
+    /// Returns the expected number of hashes needed to find a block at the given target, i.e.
+    /// `2^256 / (target + 1)`. Saturates at `u128::MAX` for targets at or below `2^128`.
+    fn expected_hashes_per_block(&self, target: &PowTarget) -> u128 {
+        target.work()
+    }
+
+    /// Returns the hash rate (in hashes per second) needed to find blocks at the given target every
+    /// `pow_target_spacing` seconds on average
+    fn expected_hashrate_for_block_time_hashes_per_second(&self, target: &PowTarget) -> f64 {
+        self.expected_hashes_per_block(target) as f64 / self.chain_params().pow_target_spacing as f64
+    }
+
+    /// Returns whether this is a `NetworkType::Mainnet` network
+    fn is_mainnet(&self) -> bool {
+        self.network_type() == NetworkType::Mainnet
+    }
+
+    /// Returns whether this is a `NetworkType::Testnet` network
+    fn is_testnet(&self) -> bool {
+        self.network_type() == NetworkType::Testnet
+    }
+
+    /// Returns whether this is a `NetworkType::Signet` network
+    fn is_signet(&self) -> bool {
+        self.network_type() == NetworkType::Signet
+    }
+
+    /// Returns whether this is a `NetworkType::Regtest` network
+    fn is_regtest(&self) -> bool {
+        self.network_type() == NetworkType::Regtest
+    }
+
+    /// Returns whether coins on this network have no real value, i.e. it isn't a mainnet
+    fn is_test_network(&self) -> bool {
+        self.network_type() != NetworkType::Mainnet
+    }
+
+    /// Returns whether this is a production network with real economic activity
+    fn is_production_network(&self) -> bool {
+        self.network_type() == NetworkType::Mainnet
+    }
+
+    /// Returns all SLIP-0132 extended key prefixes of the network
+    fn extended_key_prefixes(&self) -> ExtendedKeyPrefixes {
+        ExtendedKeyPrefixes {
+            xpub: self.xpub_prefix(),
+            xpriv: self.xpriv_prefix(),
+            ypub: self.ypub_prefix(),
+            ypriv: self.ypriv_prefix(),
+            zpub: self.zpub_prefix(),
+            zpriv: self.zpriv_prefix(),
+            ypub_multisig: self.ypub_multisig_prefix(),
+            ypriv_multisig: self.ypriv_multisig_prefix(),
+            zpub_multisig: self.zpub_multisig_prefix(),
+            zpriv_multisig: self.zpriv_multisig_prefix(),
+        }
+    }
+
+    /// Returns the Base58Check version bytes of all legacy address types
+    fn address_prefixes(&self) -> AddressPrefixes {
+        AddressPrefixes {
+            p2pk: self.p2pk_prefix(),
+            p2pkh: self.p2pkh_prefix(),
+            p2sh: self.p2sh_prefix(),
+        }
+    }
+
+    /// Returns the header of the genesis block, whose hash is `genesis_block()` on networks using
+    /// double SHA256 block hashes
+    fn genesis_block_header(&self) -> GenesisBlockHeader {
+        GenesisBlockHeader {
+            version: self.genesis_block_version(),
+            prev_blockhash: sha256d::Hash::from_slice(&[0; 32]).expect("32 bytes"),
+            merkle_root: self.genesis_merkle_root(),
+            time: self.genesis_block_timestamp(),
+            bits: self.genesis_block_bits(),
+            nonce: self.genesis_block_nonce(),
+        }
+    }
+
+    /// Returns the script block signatures have to satisfy on signets (BIP325), `None` for other
+    /// networks
+    fn challenge_script(&self) -> Option<&[u8]> {
+        None
+    }
+
+    /// Returns the block signing challenge of signets, empty for other networks. This is
+    /// [`challenge_script`](#method.challenge_script) without the `Option`.
+    fn signet_challenge(&self) -> &[u8] {
+        self.challenge_script().unwrap_or(&[])
+    }
+}
+
+/// Implements `NetworkConstants` for a pointer type by delegating every method, including the
+/// provided ones, to the pointee
+macro_rules! impl_network_constants_for_pointer {
+    ([$($generics:tt)*] $pointer:ty) => {
+        impl<$($generics)*> NetworkConstants for $pointer {
+            fn hrp(&self) -> &'static str {
+                (**self).hrp()
+            }
+
+            fn bolt11_hrp(&self) -> &'static str {
+                (**self).bolt11_hrp()
+            }
+
+            fn p2pk_prefix(&self) -> u8 {
+                (**self).p2pk_prefix()
+            }
+
+            fn p2pkh_prefix(&self) -> u8 {
+                (**self).p2pkh_prefix()
+            }
+
+            fn p2sh_prefix(&self) -> u8 {
+                (**self).p2sh_prefix()
+            }
+
+            fn xpub_prefix(&self) -> &'static [u8; 4] {
+                (**self).xpub_prefix()
+            }
+
+            fn xpriv_prefix(&self) -> &'static [u8; 4] {
+                (**self).xpriv_prefix()
+            }
+
+            fn ypub_prefix(&self) -> &'static [u8; 4] {
+                (**self).ypub_prefix()
+            }
+
+            fn ypriv_prefix(&self) -> &'static [u8; 4] {
+                (**self).ypriv_prefix()
+            }
+
+            fn zpub_prefix(&self) -> &'static [u8; 4] {
+                (**self).zpub_prefix()
+            }
+
+            fn zpriv_prefix(&self) -> &'static [u8; 4] {
+                (**self).zpriv_prefix()
+            }
+
+            fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+                (**self).ypub_multisig_prefix()
+            }
+
+            fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+                (**self).ypriv_multisig_prefix()
+            }
+
+            fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+                (**self).zpub_multisig_prefix()
+            }
+
+            fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+                (**self).zpriv_multisig_prefix()
+            }
+
+            fn wif_prefix(&self) -> u8 {
+                (**self).wif_prefix()
+            }
+
+            fn magic(&self) -> u32 {
+                (**self).magic()
+            }
+
+            fn name(&self) -> &'static str {
+                (**self).name()
+            }
+
+            fn currency_name(&self) -> &'static str {
+                (**self).currency_name()
+            }
+
+            fn short_display_name(&self) -> &'static str {
+                (**self).short_display_name()
+            }
+
+            fn ticker(&self) -> &'static str {
+                (**self).ticker()
+            }
+
+            fn default_datadir_name_unix(&self) -> &'static str {
+                (**self).default_datadir_name_unix()
+            }
+
+            fn default_datadir_name_windows(&self) -> &'static str {
+                (**self).default_datadir_name_windows()
+            }
+
+            fn network_subdir_name(&self) -> Option<&'static str> {
+                (**self).network_subdir_name()
+            }
+
+            fn network_type(&self) -> NetworkType {
+                (**self).network_type()
+            }
+
+            fn chain_params(&self) -> ChainParams {
+                (**self).chain_params()
+            }
+
+            fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+                (**self).bip9_deployments()
+            }
+
+            fn genesis_block(&self) -> sha256d::Hash {
+                (**self).genesis_block()
+            }
+
+            fn genesis_block_timestamp(&self) -> u32 {
+                (**self).genesis_block_timestamp()
+            }
+
+            fn genesis_block_nonce(&self) -> u32 {
+                (**self).genesis_block_nonce()
+            }
+
+            fn genesis_block_bits(&self) -> u32 {
+                (**self).genesis_block_bits()
+            }
+
+            fn genesis_block_version(&self) -> i32 {
+                (**self).genesis_block_version()
+            }
+
+            fn genesis_merkle_root(&self) -> sha256d::Hash {
+                (**self).genesis_merkle_root()
+            }
+
+            fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+                (**self).genesis_block_coinbase_value_satoshis()
+            }
+
+            fn genesis_coinbase_is_spendable(&self) -> bool {
+                (**self).genesis_coinbase_is_spendable()
+            }
+
+            fn minimum_chain_work_hex(&self) -> &'static str {
+                (**self).minimum_chain_work_hex()
+            }
+
+            fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+                (**self).assumed_valid_block()
+            }
+
+            fn supports_taproot(&self) -> bool {
+                (**self).supports_taproot()
+            }
+
+            fn halving_interval(&self) -> u32 {
+                (**self).halving_interval()
+            }
+
+            fn slip44_coin_type(&self) -> u32 {
+                (**self).slip44_coin_type()
+            }
+
+            fn default_port(&self) -> u16 {
+                (**self).default_port()
+            }
+
+            fn rpc_port(&self) -> u16 {
+                (**self).rpc_port()
+            }
+
+            fn coinbase_maturity(&self) -> u32 {
+                (**self).coinbase_maturity()
+            }
+
+            fn max_money(&self) -> u64 {
+                (**self).max_money()
+            }
+
+            fn initial_block_subsidy_satoshis(&self) -> u64 {
+                (**self).initial_block_subsidy_satoshis()
+            }
+
+            fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+                (**self).clone_boxed()
+            }
+
+            fn satoshis_per_bitcoin(&self) -> u64 {
+                (**self).satoshis_per_bitcoin()
+            }
+
+            fn op_return_output_value(&self) -> u64 {
+                (**self).op_return_output_value()
+            }
+
+            fn max_op_return_size_bytes(&self) -> usize {
+                (**self).max_op_return_size_bytes()
+            }
+
+            fn is_valid_op_return_output(&self, value_satoshis: u64, script_size: usize) -> bool {
+                (**self).is_valid_op_return_output(value_satoshis, script_size)
+            }
+
+            fn p2ms_max_keys_standard_output(&self) -> u8 {
+                (**self).p2ms_max_keys_standard_output()
+            }
+
+            fn p2sh_max_sigops_standard(&self) -> u8 {
+                (**self).p2sh_max_sigops_standard()
+            }
+
+            fn is_standard_p2ms_output(&self, m: u8, n: u8) -> bool {
+                (**self).is_standard_p2ms_output(m, n)
+            }
+
+            fn sequence_final(&self) -> u32 {
+                (**self).sequence_final()
+            }
+
+            fn sequence_enable_locktime(&self) -> u32 {
+                (**self).sequence_enable_locktime()
+            }
+
+            fn sequence_bip125_rbf_max(&self) -> u32 {
+                (**self).sequence_bip125_rbf_max()
+            }
+
+            fn sequence_opt_in_rbf_max(&self) -> u32 {
+                (**self).sequence_opt_in_rbf_max()
+            }
+
+            fn is_rbf_signal(&self, sequence: u32) -> bool {
+                (**self).is_rbf_signal(sequence)
+            }
+
+            fn bip125_opt_in_rbf_max_sequence(&self) -> u32 {
+                (**self).bip125_opt_in_rbf_max_sequence()
+            }
+
+            fn bip125_replacement_fee_increment_sat_per_kvb(&self) -> u64 {
+                (**self).bip125_replacement_fee_increment_sat_per_kvb()
+            }
+
+            fn bip125_is_opt_in_rbf(&self, sequence: u32) -> bool {
+                (**self).bip125_is_opt_in_rbf(sequence)
+            }
+
+            fn bip125_replacement_min_fee_bump_satoshis(
+                &self,
+                original_fee: u64,
+                replacement_size_kvb: u64,
+            ) -> u64 {
+                (**self).bip125_replacement_min_fee_bump_satoshis(
+                    original_fee,
+                    replacement_size_kvb,
+                )
+            }
+
+            fn blocks_per_era(&self) -> u32 {
+                (**self).blocks_per_era()
+            }
+
+            fn era_at_height(&self, height: u32) -> u32 {
+                (**self).era_at_height(height)
+            }
+
+            fn first_height_of_era(&self, era: u32) -> u32 {
+                (**self).first_height_of_era(era)
+            }
+
+            fn last_height_of_era(&self, era: u32) -> u32 {
+                (**self).last_height_of_era(era)
+            }
+
+            fn block_subsidy_at_height(&self, height: u32) -> u64 {
+                (**self).block_subsidy_at_height(height)
+            }
+
+            fn max_addr_message_entries(&self) -> usize {
+                (**self).max_addr_message_entries()
+            }
+
+            fn max_addrv2_message_entries(&self) -> usize {
+                (**self).max_addrv2_message_entries()
+            }
+
+            fn max_outbound_connections_default(&self) -> u32 {
+                (**self).max_outbound_connections_default()
+            }
+
+            fn max_inbound_connections_default(&self) -> u32 {
+                (**self).max_inbound_connections_default()
+            }
+
+            fn max_feeler_connections(&self) -> u32 {
+                (**self).max_feeler_connections()
+            }
+
+            fn getdata_block_timeout_seconds(&self) -> u32 {
+                (**self).getdata_block_timeout_seconds()
+            }
+
+            fn getdata_tx_timeout_seconds(&self) -> u32 {
+                (**self).getdata_tx_timeout_seconds()
+            }
+
+            fn ping_interval_seconds(&self) -> u32 {
+                (**self).ping_interval_seconds()
+            }
+
+            fn ping_timeout_seconds(&self) -> u32 {
+                (**self).ping_timeout_seconds()
+            }
+
+            fn connection_timeout_seconds(&self) -> u32 {
+                (**self).connection_timeout_seconds()
+            }
+
+            fn minimum_chain_work_bytes(&self) -> Option<[u8; 32]> {
+                (**self).minimum_chain_work_bytes()
+            }
+
+            fn bip47_version_byte(&self) -> u8 {
+                (**self).bip47_version_byte()
+            }
+
+            fn bip47_payment_code_bytes(&self) -> usize {
+                (**self).bip47_payment_code_bytes()
+            }
+
+            fn bip47_feature_bit_bitmask(&self) -> u8 {
+                (**self).bip47_feature_bit_bitmask()
+            }
+
+            fn bip47_x_coordinate_is_valid(&self, x: &[u8; 32]) -> bool {
+                (**self).bip47_x_coordinate_is_valid(x)
+            }
+
+            fn finality_confirmations(&self) -> u32 {
+                (**self).finality_confirmations()
+            }
+
+            fn small_amount_confirmations(&self) -> u32 {
+                (**self).small_amount_confirmations()
+            }
+
+            fn is_tx_final(&self, confirmations: u32) -> bool {
+                (**self).is_tx_final(confirmations)
+            }
+
+            fn confirmations_equivalent_to_bitcoin_6(&self) -> u32 {
+                (**self).confirmations_equivalent_to_bitcoin_6()
+            }
+
+            fn discourages_address_reuse(&self) -> bool {
+                (**self).discourages_address_reuse()
+            }
+
+            fn address_reuse_warning_message(&self) -> Option<&'static str> {
+                (**self).address_reuse_warning_message()
+            }
+
+            fn supported_descriptor_types(&self) -> &'static [DescriptorType] {
+                (**self).supported_descriptor_types()
+            }
+
+            fn descriptor_type_is_supported(&self, dt: DescriptorType) -> bool {
+                (**self).descriptor_type_is_supported(dt)
+            }
+
+            fn musig2_supported(&self) -> bool {
+                (**self).musig2_supported()
+            }
+
+            fn musig2_nonce_size_bytes(&self) -> usize {
+                (**self).musig2_nonce_size_bytes()
+            }
+
+            fn musig2_partial_signature_size_bytes(&self) -> usize {
+                (**self).musig2_partial_signature_size_bytes()
+            }
+
+            fn bloom_filter_max_hash_funcs(&self) -> u32 {
+                (**self).bloom_filter_max_hash_funcs()
+            }
+
+            fn bloom_filter_nflags_none(&self) -> u8 {
+                (**self).bloom_filter_nflags_none()
+            }
+
+            fn bloom_filter_nflags_all(&self) -> u8 {
+                (**self).bloom_filter_nflags_all()
+            }
+
+            fn bloom_filter_nflags_p2pubkey_only(&self) -> u8 {
+                (**self).bloom_filter_nflags_p2pubkey_only()
+            }
+
+            fn bloom_filter_max_size_bytes(&self) -> usize {
+                (**self).bloom_filter_max_size_bytes()
+            }
+
+            fn compact_size_1_byte_max(&self) -> u64 {
+                (**self).compact_size_1_byte_max()
+            }
+
+            fn compact_size_3_byte_marker(&self) -> u8 {
+                (**self).compact_size_3_byte_marker()
+            }
+
+            fn compact_size_5_byte_marker(&self) -> u8 {
+                (**self).compact_size_5_byte_marker()
+            }
+
+            fn compact_size_9_byte_marker(&self) -> u8 {
+                (**self).compact_size_9_byte_marker()
+            }
+
+            fn compact_size_encoded_length(&self, value: u64) -> usize {
+                (**self).compact_size_encoded_length(value)
+            }
+
+            fn precomputed_sha256_midstate_for_version_1_inputs(&self) -> Option<[u8; 32]> {
+                (**self).precomputed_sha256_midstate_for_version_1_inputs()
+            }
+
+            fn taproot_sighash_sha256_initial_state(&self) -> [u32; 8] {
+                (**self).taproot_sighash_sha256_initial_state()
+            }
+
+            fn segwit_commitment_header(&self) -> &'static [u8; 6] {
+                (**self).segwit_commitment_header()
+            }
+
+            fn segwit_commitment_total_size(&self) -> usize {
+                (**self).segwit_commitment_total_size()
+            }
+
+            fn segwit_witness_reserved_value(&self) -> &'static [u8; 32] {
+                (**self).segwit_witness_reserved_value()
+            }
+
+            fn segwit_commitment_is_present(&self, script: &[u8]) -> bool {
+                (**self).segwit_commitment_is_present(script)
+            }
+
+            fn bip158_filter_supported(&self) -> bool {
+                (**self).bip158_filter_supported()
+            }
+
+            fn bip158_filter_type_basic(&self) -> u8 {
+                (**self).bip158_filter_type_basic()
+            }
+
+            fn bip158_filter_parameter_m(&self) -> u64 {
+                (**self).bip158_filter_parameter_m()
+            }
+
+            fn bip158_filter_parameter_p(&self) -> u8 {
+                (**self).bip158_filter_parameter_p()
+            }
+
+            fn bip158_siphash_key_prefix(&self) -> &'static [u8; 0] {
+                (**self).bip158_siphash_key_prefix()
+            }
+
+            fn expected_hashes_per_block(&self, target: &PowTarget) -> u128 {
+                (**self).expected_hashes_per_block(target)
+            }
+
+            fn expected_hashrate_for_block_time_hashes_per_second(
+                &self,
+                target: &PowTarget,
+            ) -> f64 {
+                (**self).expected_hashrate_for_block_time_hashes_per_second(target)
+            }
+
+            fn is_mainnet(&self) -> bool {
+                (**self).is_mainnet()
+            }
+
+            fn is_testnet(&self) -> bool {
+                (**self).is_testnet()
+            }
+
+            fn is_signet(&self) -> bool {
+                (**self).is_signet()
+            }
+
+            fn is_regtest(&self) -> bool {
+                (**self).is_regtest()
+            }
+
+            fn is_test_network(&self) -> bool {
+                (**self).is_test_network()
+            }
+
+            fn is_production_network(&self) -> bool {
+                (**self).is_production_network()
+            }
+
+            fn extended_key_prefixes(&self) -> ExtendedKeyPrefixes {
+                (**self).extended_key_prefixes()
+            }
+
+            fn address_prefixes(&self) -> AddressPrefixes {
+                (**self).address_prefixes()
+            }
+
+            fn genesis_block_header(&self) -> GenesisBlockHeader {
+                (**self).genesis_block_header()
+            }
+
+            fn challenge_script(&self) -> Option<&[u8]> {
+                (**self).challenge_script()
+            }
+
+            fn signet_challenge(&self) -> &[u8] {
+                (**self).signet_challenge()
+            }
+        }
+    };
+}
+
+impl_network_constants_for_pointer!([T: NetworkConstants + ?Sized] Arc<T>);
+impl_network_constants_for_pointer!(['a, T: NetworkConstants + ?Sized] &'a T);
+
+/// Describes the nature of the network
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum NetworkType {
+    /// Public production network with real economic activity
+    Mainnet,
+
+    /// Public network without real economic activity, for testing purposes only
+    Testnet,
+
+    /// Test network whose blocks are signed by a fixed set of keys instead of being mined
+    /// competitively (BIP325)
+    Signet,
+
+    /// Private testnet, typically created and controlled by a single actor
+    Regtest,
+}
+
+impl NetworkType {
+    /// Returns the lower case name of the network type, e.g. "mainnet"
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            NetworkType::Mainnet => "mainnet",
+            NetworkType::Testnet => "testnet",
+            NetworkType::Signet => "signet",
+            NetworkType::Regtest => "regtest",
+        }
+    }
+
+    /// Returns whether coins on networks of this type have real value, only for `Mainnet`
+    pub fn is_production(&self) -> bool {
+        *self == NetworkType::Mainnet
+    }
+
+    /// Returns whether networks of this type are for testing, i.e. everything but `Mainnet`
+    pub fn is_test(&self) -> bool {
+        !self.is_production()
+    }
+
+    /// Returns all network types in their `Ord` order
+    pub fn all() -> &'static [NetworkType] {
+        &[NetworkType::Mainnet, NetworkType::Testnet, NetworkType::Signet, NetworkType::Regtest]
+    }
+}
+
+impl fmt::Display for NetworkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<NetworkType> for &'static str {
+    fn from(network_type: NetworkType) -> &'static str {
+        network_type.as_str()
+    }
+}
+
+/// Parses the names returned by `as_str()`, ignoring ASCII case
+impl<'a> TryFrom<&'a str> for NetworkType {
+    type Error = ParseNetworkTypeError;
+
+    fn try_from(s: &'a str) -> Result<NetworkType, ParseNetworkTypeError> {
+        NetworkType::all()
+            .iter()
+            .find(|t| t.as_str().eq_ignore_ascii_case(s))
+            .cloned()
+            .ok_or_else(|| ParseNetworkTypeError(s.to_string()))
+    }
+}
+
+/// Error returned when parsing a string that isn't the name of a network type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseNetworkTypeError(pub String);
+
+impl fmt::Display for ParseNetworkTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown network type: '{}'", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseNetworkTypeError {}
+
+/// Output script descriptor types as defined in BIP380 and following
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DescriptorType {
+    /// `pk()`, pay to public key (BIP381)
+    Pk,
+
+    /// `pkh()`, pay to public key hash (BIP381)
+    Pkh,
+
+    /// `wpkh()`, pay to witness public key hash (BIP382)
+    Wpkh,
+
+    /// `sh()`, pay to script hash (BIP381)
+    Sh,
+
+    /// `wsh()`, pay to witness script hash (BIP382)
+    Wsh,
+
+    /// `tr()`, pay to taproot (BIP386)
+    Tr,
+
+    /// `multi()`, multisig (BIP383)
+    Multi,
+
+    /// `sortedmulti()`, multisig with lexicographically sorted keys (BIP383)
+    Sortedmulti,
+
+    /// `combo()`, all standard scripts for a key (BIP384)
+    Combo,
+
+    /// `addr()`, a script given as address (BIP385)
+    Addr,
+
+    /// `raw()`, a script given as hex (BIP385)
+    Raw,
+}
+
+/// The Base58Check version bytes of a network's legacy addresses, see the corresponding
+/// `NetworkConstants` methods.
+///
+/// Fields may be added in minor releases, e.g. for networks with two byte prefixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AddressPrefixes {
+    /// Version byte of P2PK addresses
+    pub p2pk: u8,
+    /// Version byte of P2PKH addresses
+    pub p2pkh: u8,
+    /// Version byte of P2SH addresses
+    pub p2sh: u8,
+}
+
+/// A soft fork deployed with BIP9 version bits signalling, see
+/// `NetworkConstants::bip9_deployments`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bip9Deployment {
+    /// Name of the deployment as used by the reference node, e.g. "segwit"
+    pub name: &'static str,
+    /// Bit of the block version miners set to signal readiness
+    pub bit: u8,
+    /// Median time past from which signalling counts
+    pub start_time: u32,
+    /// Median time past after which the deployment fails if it hasn't locked in
+    pub timeout: u32,
+    /// Earliest height the deployment can activate at after locking in (BIP341 speedy trial), 0
+    /// if there is no such restriction
+    pub min_activation_height: u32,
+}
+
+/// The header of a network's genesis block, see `NetworkConstants::genesis_block_header`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenesisBlockHeader {
+    /// Block version
+    pub version: i32,
+    /// Hash of the previous block, all zeros for the genesis block
+    pub prev_blockhash: sha256d::Hash,
+    /// Merkle root of the block's transactions, i.e. the coinbase transaction's hash
+    pub merkle_root: sha256d::Hash,
+    /// Block timestamp in seconds since the Unix epoch
+    pub time: u32,
+    /// Proof of work target in compact `nBits` format
+    pub bits: u32,
+    /// Nonce found by the miner
+    pub nonce: u32,
+}
+
+/// The SLIP-0132 extended key prefixes of a network, see the corresponding `NetworkConstants`
+/// methods
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtendedKeyPrefixes {
+    /// Public keys for P2PKH (BIP44)
+    pub xpub: &'static [u8; 4],
+    /// Private keys for P2PKH (BIP44)
+    pub xpriv: &'static [u8; 4],
+    /// Public keys for P2WPKH nested in P2SH (BIP49)
+    pub ypub: &'static [u8; 4],
+    /// Private keys for P2WPKH nested in P2SH (BIP49)
+    pub ypriv: &'static [u8; 4],
+    /// Public keys for native P2WPKH (BIP84)
+    pub zpub: &'static [u8; 4],
+    /// Private keys for native P2WPKH (BIP84)
+    pub zpriv: &'static [u8; 4],
+    /// Public keys for multisig P2WSH nested in P2SH
+    pub ypub_multisig: &'static [u8; 4],
+    /// Private keys for multisig P2WSH nested in P2SH
+    pub ypriv_multisig: &'static [u8; 4],
+    /// Public keys for multisig native P2WSH
+    pub zpub_multisig: &'static [u8; 4],
+    /// Private keys for multisig native P2WSH
+    pub zpriv_multisig: &'static [u8; 4],
+}
+
+/// Parameters that influence chain consensus.
+///
+/// New parameters may be added in minor releases, so `ChainParams` can't be constructed from a
+/// struct literal outside of this crate. Custom networks can start from the parameters of an
+/// existing network and modify its public fields instead.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub struct ChainParams {
+    /// Time when BIP16 becomes active.
+    pub bip16_time: u32,
+
+    /// Block height at which BIP34 becomes active, `u32::MAX` if the height isn't tracked for
+    /// the network.
+    pub bip34_height: u32,
+
+    /// Block height at which BIP65 becomes active, `u32::MAX` if the height isn't tracked for
+    /// the network.
+    pub bip65_height: u32,
+
+    /// Block height at which BIP66 becomes active, `u32::MAX` if the height isn't tracked for
+    /// the network.
+    pub bip66_height: u32,
+
+    /// Block height at which relative lock-times via `nSequence` (BIP68) become active. BIP68
+    /// was deployed together with BIP112 and BIP113 (median time past for lock-times) in the
+    /// "CSV soft fork", so it equals `csv_height`. `u32::MAX` if it never becomes active or the
+    /// height isn't tracked for the network.
+    pub bip68_height: u32,
+
+    /// Block height at which `OP_CHECKSEQUENCEVERIFY` (BIP112) becomes active, `u32::MAX` if it
+    /// never does or the height isn't tracked for the network.
+    pub csv_height: u32,
+
+    /// Block height at which segregated witness (BIP141) becomes active, `u32::MAX` if it never
+    /// does or the height isn't tracked for the network.
+    pub segwit_height: u32,
+
+    /// Block height at which Taproot (BIP341 and BIP342) becomes active, `u32::MAX` if it never
+    /// does or the height isn't tracked for the network.
+    pub taproot_height: u32,
+
+    /// Minimum blocks including miner confirmation of the total of 2016 blocks in a retargeting period,
+    /// (nPowTargetTimespan / nPowTargetSpacing) which is also used for BIP9 deployments.
+    /// Examples: 1916 for 95%, 1512 for testchains.
+    pub rule_change_activation_threshold: u32,
+
+    /// Number of blocks with the same set of rules.
+    pub miner_confirmation_window: u32,
+
+    /// Proof of work limit value. It cointans the lowest possible difficulty.
+    pub pow_limit: [u64; 4],
+
+    /// Expected amount of time to mine one block.
+    pub pow_target_spacing: u64,
+
+    /// Difficulty recalculation interval.
+    pub pow_target_timespan: u64,
+
+    /// Determines whether minimal difficulty may be used for blocks or not.
+    pub allow_min_difficulty_blocks: bool,
+
+    /// Determines whether retargeting is disabled for this network or not.
+    pub no_pow_retargeting: bool,
+
+    /// Maximum weight of a block (BIP141). Networks without segwit use 4 × their maximum block
+    /// size.
+    pub max_block_weight: u32,
+
+    /// Factor by which non-witness data is weighted more heavily than witness data, 1 for networks
+    /// without segwit.
+    pub witness_scale_factor: u32,
+
+    /// Value below which an output is considered dust by the reference node's default relay
+    /// policy (546 for a bitcoin P2PKH output). This is not a consensus rule: transactions creating
+    /// dust outputs are valid in blocks, and nodes may be configured with a different limit (some
+    /// regtest setups use 0).
+    pub dust_limit_satoshis: u64,
+
+    /// Default minimum fee rate in sat/kvB a transaction has to pay to be relayed and accepted
+    /// into the mempool (`-minrelaytxfee`). This is the reference node's default policy, a node
+    /// operator may have configured a different value.
+    pub min_relay_tx_fee_rate: u64,
+
+    /// Maximum signature operation cost of a block (`MAX_BLOCK_SIGOPS_COST`, BIP141). Legacy
+    /// sigops count `witness_scale_factor` times, so this allows 20 000 of them on bitcoin. On
+    /// networks without segwit it's the plain 20 000 sigops limit (`MAX_BLOCK_SIGOPS`).
+    pub max_block_sigops: u32,
+
+    /// Maximum size of a block in bytes without its witness data, the consensus limit on networks
+    /// without segwit. For segwit networks it's implied by `max_block_weight` and kept for legacy
+    /// calculations (`MAX_BLOCK_BASE_SIZE`, BIP141).
+    pub max_block_size: u32,
+}
+
+impl ChainParams {
+    /// Returns the number of blocks between difficulty adjustments, 2016 for bitcoin. Returns
+    /// `None` if `pow_target_spacing` is zero, see [`validate`](#method.validate).
+    pub fn difficulty_adjustment_interval(&self) -> Option<u64> {
+        self.pow_target_timespan.checked_div(self.pow_target_spacing)
+    }
+
+    /// Returns `pow_limit` as a 32 byte big endian number, the byte order targets are usually
+    /// displayed in
+    pub fn pow_limit_as_big_endian_bytes(&self) -> [u8; 32] {
+        be_bytes_from_words(&self.pow_limit)
+    }
+
+    /// Returns `pow_limit` in the compact `nBits` format of block headers, 0x1d00ffff for
+    /// bitcoin. See [`PowTarget::to_compact`](struct.PowTarget.html#method.to_compact).
+    pub fn pow_limit_compact(&self) -> u32 {
+        PowTarget(self.pow_limit).to_compact()
+    }
+
+    /// Returns the time between difficulty adjustments in seconds, `pow_target_timespan`
+    pub fn retarget_interval_seconds(&self) -> u64 {
+        self.pow_target_timespan
+    }
+
+    /// Returns the number of blocks expected to be mined per day, 144 for bitcoin. Returns `None`
+    /// if `pow_target_spacing` is zero.
+    pub fn expected_blocks_per_day(&self) -> Option<u64> {
+        86400u64.checked_div(self.pow_target_spacing)
+    }
+
+    /// Returns the number of blocks expected to be mined per 365 day year, 52560 for bitcoin.
+    /// Returns `None` if `pow_target_spacing` is zero.
+    pub fn expected_blocks_per_year(&self) -> Option<u64> {
+        (365 * 86400u64).checked_div(self.pow_target_spacing)
+    }
+
+    /// Returns the heights at which the block subsidy halves, from the first halving up to the
+    /// one that shifts `initial_subsidy` to 0, e.g. 210000, 420000, ... 6930000 for bitcoin.
+    /// Heights that don't fit a `u32` are left out. A `halving_interval` of 0 or `u32::MAX` means
+    /// the subsidy never halves and yields no heights, like
+    /// [`total_supply_at_height`](#method.total_supply_at_height).
+    pub fn halving_heights(
+        &self,
+        initial_subsidy: u64,
+        halving_interval: u32,
+    ) -> impl Iterator<Item = u32> {
+        let halvings = if halving_interval == 0 || halving_interval == u32::MAX {
+            0
+        } else {
+            64 - initial_subsidy.leading_zeros()
+        };
+        (1..=halvings)
+            .take_while(move |&halving| halving <= u32::MAX / halving_interval)
+            .map(move |halving| halving * halving_interval)
+    }
+
+    /// Returns the sum of the block subsidies of all blocks from the genesis block up to and
+    /// including `height`, in the base unit, with the subsidy halving every `halving_interval`
+    /// blocks (never if it's 0 or `u32::MAX`). The genesis block is included even on networks
+    /// where its coinbase can't be spent. Since every halving rounds down, the sum ends up
+    /// slightly below the nominal supply, e.g. 20999999.9769 BTC instead of the 21 million of
+    /// bitcoin's `max_money()`. The result saturates at `u64::MAX`.
+    pub fn total_supply_at_height(
+        &self,
+        height: u32,
+        initial_subsidy: u64,
+        halving_interval: u32,
+    ) -> u64 {
+        if halving_interval == 0 || halving_interval == u32::MAX {
+            return initial_subsidy.saturating_mul(u64::from(height) + 1);
+        }
+        let mut total = 0u64;
+        let last_era = height / halving_interval;
+        for era in 0..=last_era.min(63) {
+            let first = era * halving_interval;
+            let last = if era == last_era { height } else { first + (halving_interval - 1) };
+            let blocks = u64::from(last - first) + 1;
+            total = total.saturating_add(blocks.saturating_mul(initial_subsidy >> era));
+        }
+        total
+    }
+
+    /// Returns true if BIP34 (height in coinbase) is enforced at `height`
+    pub fn is_bip34_active(&self, height: u32) -> bool {
+        height >= self.bip34_height
+    }
+
+    /// Returns true if BIP65 (OP_CHECKLOCKTIMEVERIFY) is enforced at `height`
+    pub fn is_bip65_active(&self, height: u32) -> bool {
+        height >= self.bip65_height
+    }
+
+    /// Returns true if BIP66 (strict DER signatures) is enforced at `height`
+    pub fn is_bip66_active(&self, height: u32) -> bool {
+        height >= self.bip66_height
+    }
+
+    /// Checks the parameters for internal consistency
+    pub fn validate(&self) -> Result<(), ChainParamsError> {
+        if self.pow_target_spacing == 0 {
+            return Err(ChainParamsError::ZeroPowTargetSpacing);
+        }
+        if self.pow_target_timespan < self.pow_target_spacing {
+            return Err(ChainParamsError::PowTargetTimespanBelowSpacing);
+        }
+        if self.miner_confirmation_window == 0 {
+            return Err(ChainParamsError::ZeroMinerConfirmationWindow);
+        }
+        if self.rule_change_activation_threshold > self.miner_confirmation_window {
+            return Err(ChainParamsError::ActivationThresholdAboveWindow);
+        }
+        if self.pow_limit == [0; 4] {
+            return Err(ChainParamsError::ZeroPowLimit);
+        }
+        Ok(())
+    }
+}
+
+/// Inconsistency found by `ChainParams::validate`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainParamsError {
+    /// `pow_target_spacing` is zero
+    ZeroPowTargetSpacing,
+    /// `pow_target_timespan` is shorter than `pow_target_spacing`
+    PowTargetTimespanBelowSpacing,
+    /// `miner_confirmation_window` is zero
+    ZeroMinerConfirmationWindow,
+    /// `rule_change_activation_threshold` is larger than `miner_confirmation_window`
+    ActivationThresholdAboveWindow,
+    /// `pow_limit` is zero, no block hash could meet it
+    ZeroPowLimit,
+}
+
+impl fmt::Display for ChainParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            ChainParamsError::ZeroPowTargetSpacing => "pow target spacing is zero",
+            ChainParamsError::PowTargetTimespanBelowSpacing => {
+                "pow target timespan is shorter than the pow target spacing"
+            }
+            ChainParamsError::ZeroMinerConfirmationWindow => "miner confirmation window is zero",
+            ChainParamsError::ActivationThresholdAboveWindow => {
+                "rule change activation threshold exceeds the miner confirmation window"
+            }
+            ChainParamsError::ZeroPowLimit => "pow limit is zero",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChainParamsError {}
+
+/// A 256-bit proof of work target, a block hash has to be lower than or equal to it to be valid.
+///
+/// The words are stored least significant first, like `ChainParams::pow_limit`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PowTarget(pub [u64; 4]);
+
+impl PowTarget {
+    /// Decodes a target from the compact `nBits` encoding used in block headers: the most
+    /// significant byte is the length of the target in bytes, the lower three bytes are its most
+    /// significant bytes. The sign bit (0x00800000) is ignored, as is anything shifted past 256
+    /// bits.
+    pub fn from_compact(bits: u32) -> PowTarget {
+        let size = (bits >> 24) as usize;
+        let mantissa = (bits & 0x007fffff).to_be_bytes();
+        let mut bytes = [0u8; 32];
+        for (i, byte) in mantissa[1..].iter().enumerate() {
+            // position of the byte counted from the least significant end, bytes shifted below it
+            // or past 256 bits are dropped
+            match size.checked_sub(1 + i) {
+                Some(position) if position < 32 => bytes[31 - position] = *byte,
+                _ => {}
+            }
+        }
+        PowTarget(words_from_be_bytes(&bytes))
+    }
+
+    /// Encodes the target in the compact `nBits` format, losing all but its three most significant
+    /// bytes, e.g. 0x1d00ffff for the bitcoin mainnet `pow_limit`
+    pub fn to_compact(&self) -> u32 {
+        let bytes = be_bytes_from_words(&self.0);
+        let mut size = bytes.iter().position(|b| *b != 0).map_or(0, |i| 32 - i);
+        let mut mantissa = [0u8; 4];
+        for i in 0..3 {
+            if i < size {
+                mantissa[i + 1] = bytes[32 - size + i];
+            }
+        }
+        let mut compact = u32::from_be_bytes(mantissa);
+        // the mantissa is signed, move it one byte down if the sign bit would be set
+        if compact & 0x00800000 != 0 {
+            compact >>= 8;
+            size += 1;
+        }
+        compact | (size as u32) << 24
+    }
+
+    /// Returns the expected number of hashes needed to meet this target, `2^256 / (target + 1)`,
+    /// saturating at `u128::MAX`
+    fn work(&self) -> u128 {
+        // 2^256 / (t + 1) == (2^256 - 1 - t) / (t + 1) + 1, which avoids 257 bit arithmetic
+        let mut divisor = self.0;
+        if !add_one(&mut divisor) {
+            // target is 2^256 - 1, every hash meets it
+            return 1;
+        }
+        let mut dividend = self.0;
+        for word in dividend.iter_mut() {
+            *word = !*word;
+        }
+
+        let quotient = div(&dividend, &divisor);
+        if quotient[2] != 0 || quotient[3] != 0 {
+            return u128::MAX;
+        }
+        ((quotient[1] as u128) << 64 | quotient[0] as u128).saturating_add(1)
+    }
+}
+
+/// Converts a 256 bit number stored as words least significant first to big endian bytes
+fn be_bytes_from_words(words: &[u64; 4]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, word) in words.iter().rev().enumerate() {
+        bytes[i * 8..(i + 1) * 8].copy_from_slice(&word.to_be_bytes());
+    }
+    bytes
+}
+
+/// Converts a big endian 256 bit number to words stored least significant first
+fn words_from_be_bytes(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut words = [0u64; 4];
+    for (i, word) in words.iter_mut().enumerate() {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&bytes[(3 - i) * 8..(4 - i) * 8]);
+        *word = u64::from_be_bytes(buf);
+    }
+    words
+}
+
+/// Adds one to a little endian 256 bit number, returns `false` on overflow
+fn add_one(n: &mut [u64; 4]) -> bool {
+    for word in n.iter_mut() {
+        let (sum, carry) = word.overflowing_add(1);
+        *word = sum;
+        if !carry {
+            return true;
+        }
+    }
+    false
+}
+
+/// Divides two little endian 256 bit numbers using binary long division, `divisor` must not be 0
+fn div(dividend: &[u64; 4], divisor: &[u64; 4]) -> [u64; 4] {
+    let mut quotient = [0u64; 4];
+    let mut remainder = [0u64; 4];
+    for bit in (0..256).rev() {
+        // remainder = remainder << 1 | next dividend bit
+        let overflow = remainder[3] >> 63 == 1;
+        for i in (1..4).rev() {
+            remainder[i] = remainder[i] << 1 | remainder[i - 1] >> 63;
+        }
+        remainder[0] = remainder[0] << 1 | (dividend[bit / 64] >> (bit % 64)) & 1;
+
+        if overflow || !less_than(&remainder, divisor) {
+            let mut borrow = false;
+            for i in 0..4 {
+                let (diff, b1) = remainder[i].overflowing_sub(divisor[i]);
+                let (diff, b2) = diff.overflowing_sub(borrow as u64);
+                remainder[i] = diff;
+                borrow = b1 || b2;
+            }
+            quotient[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+    quotient
+}
+
+/// Compares two little endian 256 bit numbers
+fn less_than(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use ::{
+        Bip9Deployment, ChainParamsError, DescriptorType, GenesisBlockHeader, Network,
+        NetworkBuildError, NetworkBuilder, NetworkConstants, NetworkKind, NetworkRegistry,
+        NetworkType, ParseNetworkError, ParseNetworkTypeError, PowTarget, UnknownHrp, UnknownMagic,
+    };
+    use networks;
+    use bitcoin_hashes::{sha256, sha256d, Hash, HashEngine};
+    use std::collections::{BTreeMap, HashMap};
+    use std::convert::TryFrom;
+    use std::error::Error;
+    use std::sync::Arc;
+
+    fn all_networks() -> Vec<Network> {
+        vec![
+            Network::bitcoin(),
+            Network::bitcoin_testnet(),
+            Network::bitcoin_testnet4(),
+            Network::bitcoin_signet(),
+            Network::bitcoin_regtest(),
+            Network::litecoin(),
+            Network::litecoin_testnet(),
+            Network::dogecoin(),
+            Network::dogecoin_testnet(),
+            Network::namecoin(),
+            Network::vertcoin(),
+            Network::vertcoin_testnet(),
+            Network::groestlcoin(),
+            Network::ravencoin(),
+            Network::ravencoin_testnet(),
+            Network::digibyte(),
+            Network::feathercoin(),
+            Network::feathercoin_testnet(),
+            Network::monacoin(),
+            Network::monacoin_testnet(),
+        ]
+    }
+
+    #[test]
+    fn debug() {
+        for n in all_networks() {
+            assert!(format!("{:?}", n).contains(n.name()));
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", Network::bitcoin()), "bitcoin");
+        for n in vec![Network::bitcoin(), Network::bitcoin_testnet(), Network::bitcoin_regtest()] {
+            assert_eq!(n.to_string(), n.name());
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        for n in all_networks() {
+            assert_eq!(n.to_string().parse::<Network>().unwrap(), n);
+        }
+        assert_eq!("bitcoin-mainnet".parse::<Network>(), Err(ParseNetworkError("bitcoin-mainnet".to_string())));
+    }
+
+    #[test]
+    fn parse_network_error() {
+        let err = "dogecash".parse::<Network>().unwrap_err();
+        assert_eq!(err.to_string(), "unknown network: 'dogecash'");
+        fn assert_error<E: Error + Send + Sync + 'static>(_: &E) {}
+        assert_error(&err);
+        let boxed: Box<dyn Error> = Box::new(err);
+        assert_eq!(boxed.to_string(), "unknown network: 'dogecash'");
+        assert_eq!(NetworkBuildError::MissingHrp.to_string(), "network hrp not set");
+        assert_eq!(
+            NetworkBuildError::InvalidMinimumChainWork.to_string(),
+            "minimum chain work is not a hex number of at most 64 digits"
+        );
+        assert_eq!(ChainParamsError::ZeroPowLimit.to_string(), "pow limit is zero");
+    }
+
+    #[test]
+    fn try_from_magic_and_hrp() {
+        assert_eq!(Network::try_from_magic(0xD9B4BEF9), Ok(Network::bitcoin()));
+        let err = Network::try_from_magic(0xDEADBEEF).unwrap_err();
+        assert_eq!(err, UnknownMagic(0xDEADBEEF));
+        assert_eq!(err.to_string(), "unknown network magic: 0xDEADBEEF");
+
+        assert_eq!(Network::try_from_hrp("bc"), Ok(Network::bitcoin()));
+        let err = Network::try_from_hrp("xyz").unwrap_err();
+        assert_eq!(err, UnknownHrp("xyz".to_owned()));
+        assert_eq!(err.to_string(), "unknown network hrp: 'xyz'");
+        assert!(Network::try_from_hrp("").is_err());
+
+        fn assert_error<E: Error + Send + Sync + 'static>(_: &E) {}
+        assert_error(&UnknownMagic(0));
+        assert_error(&err);
+    }
+
+    #[test]
+    fn from_hrp() {
+        assert_eq!(Network::from_hrp("bc"), Some(Network::bitcoin()));
+        assert_eq!(Network::from_hrp("tb"), Some(Network::bitcoin_testnet()));
+        assert_eq!(Network::from_hrp("bcrt"), Some(Network::bitcoin_regtest()));
+        assert_eq!(Network::from_hrp("xyz"), None);
+        assert_eq!(Network::from_hrp(""), None);
+    }
+
+    #[test]
+    fn from_magic() {
+        assert_eq!(Network::from_magic(0xD9B4BEF9), Some(Network::bitcoin()));
+        assert_eq!(Network::from_magic(0x0709110B), Some(Network::bitcoin_testnet()));
+        assert_eq!(Network::from_magic(0xDAB5BFFA), Some(Network::bitcoin_regtest()));
+        assert_eq!(Network::from_magic(0x283F161C), Some(Network::bitcoin_testnet4()));
+        assert_eq!(Network::from_magic(0xDEADBEEF), None);
+    }
+
+    #[test]
+    fn bitcoin_testnet4() {
+        let testnet4 = Network::bitcoin_testnet4();
+        assert_ne!(testnet4, Network::bitcoin_testnet());
+        assert_eq!(testnet4.hrp(), Network::bitcoin_testnet().hrp());
+        assert_eq!(testnet4.network_type(), NetworkType::Testnet);
+        assert_eq!(testnet4.default_port(), 48333);
+        assert_eq!(testnet4.network_subdir_name(), Some("testnet4"));
+        assert_eq!(format!("{:?}", testnet4.kind()), "BitcoinTestnet4");
+        assert_eq!(Network::from_name("bitcoin-testnet4"), Some(testnet4));
+        // both testnets share the hrp, the older one is found first
+        assert_eq!(Network::from_hrp("tb"), Some(Network::bitcoin_testnet()));
+    }
+
+    #[test]
+    fn bitcoin_signet_custom() {
+        let signet = Network::bitcoin_signet();
+        assert_eq!(signet.magic(), 0x40CF030A);
+        assert_eq!(signet.network_type(), NetworkType::Signet);
+        assert_eq!(signet.display_name(), "Bitcoin Signet");
+
+        let challenge = vec![0x51, 0x21, 0x02, 0x42];
+        let custom = Network::bitcoin_signet_custom(challenge.clone());
+        assert_eq!(custom.challenge_script(), Some(&challenge[..]));
+        assert_eq!(custom.clone().challenge_script(), Some(&challenge[..]));
+        assert_eq!(custom.clone_boxed().challenge_script(), Some(&challenge[..]));
+        assert_ne!(custom.magic(), signet.magic());
+        assert_eq!(custom.genesis_block(), signet.genesis_block());
+        assert_eq!(custom.hrp(), "tb");
+
+        // the magic is derived from the challenge like for the default signet
+        let default_challenge = signet.challenge_script().unwrap().to_vec();
+        assert_eq!(Network::bitcoin_signet_custom(default_challenge).magic(), signet.magic());
+        assert_eq!(Network::bitcoin().challenge_script(), None);
+
+        // custom signets with different challenges are different networks
+        let other = Network::bitcoin_signet_custom(vec![0x51]);
+        assert_eq!(custom, Network::bitcoin_signet_custom(challenge));
+        assert_ne!(custom, other);
+        assert_ne!(custom.cmp(&other), ::std::cmp::Ordering::Equal);
+        let mut map = HashMap::new();
+        map.insert(custom.clone(), 1);
+        map.insert(other.clone(), 2);
+        assert_eq!(map.len(), 2);
+        let set: BTreeMap<Network, ()> = vec![(custom, ()), (other, ())].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn signet_challenge() {
+        let signet = Network::bitcoin_signet();
+        let challenge = signet.signet_challenge();
+        assert!(!challenge.is_empty());
+        assert_eq!(challenge, &networks::DEFAULT_SIGNET_CHALLENGE[..]);
+        // OP_1 <pubkey> <pubkey> OP_2 OP_CHECKMULTISIG
+        assert_eq!((challenge[0], challenge[challenge.len() - 1]), (0x51, 0xae));
+        assert!(Network::bitcoin().signet_challenge().is_empty());
+        let custom = Network::bitcoin_signet_custom(vec![0x51]);
+        assert_eq!(custom.signet_challenge(), &[0x51][..]);
+        assert_eq!(Some(custom.signet_challenge()), custom.challenge_script());
+    }
+
+    #[test]
+    fn assumed_valid_block() {
+        let block = Network::bitcoin().assumed_valid_block().unwrap();
+        // a mined block hash has at least as many leading zero bits as the pow limit
+        assert_eq!(block[24..], [0; 8]);
+        assert_ne!(block, Network::bitcoin().genesis_block());
+        assert_eq!(Network::bitcoin_signet().assumed_valid_block(), None);
+        assert_eq!(Network::bitcoin_regtest().assumed_valid_block(), None);
+    }
+
+    #[test]
+    fn from_genesis_hash() {
+        for n in Network::all_bitcoin() {
+            assert_eq!(Network::from_genesis_hash(n.genesis_block()), Some(n.clone()));
+        }
+        let zero = sha256d::Hash::from_slice(&[0; 32]).unwrap();
+        assert_eq!(Network::from_genesis_hash(zero), None);
+    }
+
+    #[test]
+    fn from_name() {
+        for n in all_networks() {
+            assert_eq!(Network::from_name(n.name()), Some(n));
+        }
+        assert_eq!(Network::from_name("Bitcoin"), None);
+        assert_eq!(Network::from_name("bitcoin-mainnet"), None);
+    }
+
+    #[test]
+    fn kind() {
+        assert!(matches!(Network::bitcoin().kind(), NetworkKind::Bitcoin));
+        assert_eq!(format!("{:?}", Network::bitcoin().kind()), "Bitcoin");
+        let custom = Network::from_box(networks::Bitcoin::new());
+        assert!(matches!(custom.kind(), NetworkKind::Custom(_)));
+        assert_eq!(format!("{:?}", custom.kind()), "Custom(bitcoin)");
+        assert_eq!(custom, Network::bitcoin());
+    }
+
+    #[test]
+    fn const_networks() {
+        static NETWORK: networks::Bitcoin = networks::BITCOIN;
+        assert_eq!(NETWORK.name(), "bitcoin");
+        assert_eq!(networks::BITCOIN_TESTNET.chain_params().segwit_height, 834624);
+        assert_eq!(networks::BITCOIN_REGTEST.network_type(), NetworkType::Regtest);
+    }
+
+    #[test]
+    fn iter() {
+        let built_in = all_networks();
+        let networks: Vec<Network> = Network::iter().collect();
+        assert!(!networks.is_empty());
+        assert!(networks.len() >= built_in.len());
+        assert_eq!(networks[..built_in.len()], built_in[..]);
+    }
+
+    #[test]
+    fn all_bitcoin() {
+        let networks: Vec<Network> = Network::all_bitcoin().collect();
+        assert_eq!(
+            networks,
+            vec![
+                Network::bitcoin(),
+                Network::bitcoin_testnet(),
+                Network::bitcoin_testnet4(),
+                Network::bitcoin_signet(),
+                Network::bitcoin_regtest(),
+            ]
+        );
+        assert!(networks.iter().all(|n| n.currency_name() == "bitcoin"));
+    }
+
+    /// Returns the names of the built-in networks sharing a value of `key` with another one,
+    /// grouped by that value
+    fn shared<K: ::std::hash::Hash + Eq, F: Fn(&Network) -> K>(key: F) -> Vec<Vec<&'static str>> {
+        let mut groups: HashMap<K, Vec<&'static str>> = HashMap::new();
+        for n in Network::iter().filter(|n| !matches!(n.kind(), NetworkKind::Custom(_))) {
+            groups.entry(key(&n)).or_default().push(n.name());
+        }
+        let mut shared: Vec<Vec<&'static str>> =
+            groups.into_iter().map(|(_, names)| names).filter(|names| names.len() > 1).collect();
+        shared.sort();
+        shared
+    }
+
+    #[test]
+    fn unique_identifiers() {
+        assert!(shared(|n| n.name()).is_empty());
+        // monacoin kept litecoin's message start bytes
+        assert_eq!(shared(|n| n.magic()), vec![vec!["litecoin", "monacoin"]]);
+        // feathercoin started from litecoin's genesis block
+        let genesis_blocks = shared(|n| n.genesis_block()[..].to_vec());
+        assert_eq!(genesis_blocks, vec![vec!["litecoin", "feathercoin"]]);
+        // the bitcoin test networks share their address constants, dogecoin has no bech32 hrp
+        assert_eq!(
+            shared(|n| n.hrp()),
+            vec![
+                vec!["bitcoin-testnet", "bitcoin-testnet4", "bitcoin-signet"],
+                vec!["dogecoin", "dogecoin-testnet"],
+            ]
+        );
+    }
+
+    #[test]
+    fn network_registry() {
+        let mut registry = NetworkRegistry::new();
+        assert_eq!(registry.get_by_name("bitcoin-regtest"), None);
+        registry.register(Box::new(networks::BITCOIN_REGTEST));
+        let regtest = Some(Network::bitcoin_regtest());
+        assert_eq!(registry.get_by_name("bitcoin-regtest"), regtest);
+        assert_eq!(registry.get_by_hrp("bcrt"), regtest);
+        assert_eq!(registry.get_by_magic(0xDAB5BFFA), regtest);
+        assert!(matches!(registry.get_by_hrp("bcrt").unwrap().kind(), NetworkKind::Custom(_)));
+        assert_eq!(registry.get_by_hrp(""), None);
+        assert_eq!(registry.get_by_magic(0xDEADBEEF), None);
+        assert!(::std::ptr::eq(NetworkRegistry::global(), NetworkRegistry::global()));
+    }
+
+    #[test]
+    fn network_builder() {
+        assert_eq!(NetworkBuilder::new().build().unwrap_err(), NetworkBuildError::MissingName);
+        assert_eq!(
+            NetworkBuilder::new().name("testcoin").magic(0x54455354).build().unwrap_err(),
+            NetworkBuildError::MissingHrp
+        );
+        assert_eq!(
+            NetworkBuilder::new().name("testcoin").hrp("test").build().unwrap_err(),
+            NetworkBuildError::MissingMagic
+        );
+        let builder = NetworkBuilder::new().name("testcoin").hrp("test").magic(0x54455354);
+        let invalid = [
+            "",
+            "0x",
+            "0x0g",
+            "000000000000000000000000000000000000000000000000000000000000000g",
+            "000000000000000000000000000000000000000000000000000000000000000000",
+        ];
+        for work in invalid.iter() {
+            assert_eq!(
+                builder.clone().minimum_chain_work_hex(work).build().unwrap_err(),
+                NetworkBuildError::InvalidMinimumChainWork
+            );
+        }
+        let work = "00000000000000000000000000000000000000000000000000000000000000Ff";
+        let network = builder.minimum_chain_work_hex(work).build().unwrap();
+        assert_eq!(network.minimum_chain_work_bytes().unwrap()[31], 0xFF);
+
+        let mut params = Network::bitcoin_regtest().chain_params();
+        params.bip34_height = 1;
+        let genesis = sha256d::Hash::hash(b"testcoin");
+        let testcoin = NetworkBuilder::new()
+            .name("testcoin")
+            .hrp("test")
+            .magic(0x54455354)
+            .p2pk_prefix(0x01)
+            .p2pkh_prefix(0x42)
+            .p2sh_prefix(0x43)
+            .xpub_prefix(&[0x01, 0x02, 0x03, 0x04])
+            .xpriv_prefix(&[0x05, 0x06, 0x07, 0x08])
+            .wif_prefix(0xc2)
+            .currency_name("testcoin")
+            .short_display_name("Testcoin")
+            .ticker("TST")
+            .default_datadir_name_unix(".testcoin")
+            .default_datadir_name_windows("Testcoin")
+            .network_subdir_name(Some("test"))
+            .network_type(NetworkType::Testnet)
+            .chain_params(params)
+            .genesis_block(genesis)
+            .genesis_block_timestamp(1700000000)
+            .genesis_block_nonce(7)
+            .genesis_block_bits(0x207fffff)
+            .genesis_block_version(4)
+            .genesis_merkle_root(genesis)
+            .genesis_block_coinbase_value_satoshis(1000)
+            .genesis_coinbase_is_spendable(true)
+            .minimum_chain_work_hex("0000000000000000000000000000000000000000000000000000000000000000")
+            .supports_taproot(false)
+            .halving_interval(u32::MAX)
+            .slip44_coin_type(1)
+            .default_port(12345)
+            .rpc_port(12346)
+            .coinbase_maturity(10)
+            .max_money(1_000_000)
+            .initial_block_subsidy_satoshis(1000)
+            .build()
+            .unwrap();
+
+        assert_eq!(testcoin.name(), "testcoin");
+        assert_eq!(testcoin.hrp(), "test");
+        assert_eq!(testcoin.magic(), 0x54455354);
+        assert_eq!(testcoin.p2pk_prefix(), 0x01);
+        assert_eq!(testcoin.p2pkh_prefix(), 0x42);
+        assert_eq!(testcoin.p2sh_prefix(), 0x43);
+        assert_eq!(testcoin.xpub_prefix(), &[0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(testcoin.xpriv_prefix(), &[0x05, 0x06, 0x07, 0x08]);
+        assert_eq!(testcoin.wif_prefix(), 0xc2);
+        assert_eq!(testcoin.currency_name(), "testcoin");
+        assert_eq!(testcoin.short_display_name(), "Testcoin");
+        assert_eq!(testcoin.ticker(), "TST");
+        assert_eq!(testcoin.default_datadir_name_unix(), ".testcoin");
+        assert_eq!(testcoin.default_datadir_name_windows(), "Testcoin");
+        assert_eq!(testcoin.network_subdir_name(), Some("test"));
+        assert_eq!(testcoin.network_type(), NetworkType::Testnet);
+        assert_eq!(testcoin.chain_params().bip34_height, 1);
+        assert_eq!(testcoin.genesis_block(), genesis);
+        assert_eq!(testcoin.genesis_block_timestamp(), 1700000000);
+        assert_eq!(testcoin.genesis_block_nonce(), 7);
+        assert_eq!(testcoin.genesis_block_bits(), 0x207fffff);
+        assert_eq!(testcoin.genesis_block_version(), 4);
+        assert_eq!(testcoin.genesis_merkle_root(), genesis);
+        assert_eq!(testcoin.genesis_block_coinbase_value_satoshis(), 1000);
+        assert!(testcoin.genesis_coinbase_is_spendable());
+        assert_eq!(testcoin.minimum_chain_work_bytes(), Some([0; 32]));
+        assert!(!testcoin.supports_taproot());
+        assert_eq!(testcoin.halving_interval(), u32::MAX);
+        assert_eq!(testcoin.slip44_coin_type(), 1);
+        assert_eq!(testcoin.default_port(), 12345);
+        assert_eq!(testcoin.rpc_port(), 12346);
+        assert_eq!(testcoin.coinbase_maturity(), 10);
+        assert_eq!(testcoin.max_money(), 1_000_000);
+        assert_eq!(testcoin.initial_block_subsidy_satoshis(), 1000);
+
+        // Unset constants are the bitcoin mainnet's
+        let minimal = NetworkBuilder::new().name("minimal").hrp("min").magic(1).build().unwrap();
+        assert_eq!(minimal.default_port(), 8333);
+
+        assert_eq!(Network::from_name("testcoin"), None);
+        NetworkRegistry::global().write().unwrap().register(Box::new(testcoin.clone()));
+        assert_eq!(Network::from_name("testcoin"), Some(Network::from_box(Box::new(testcoin))));
+        assert_eq!(Network::from_hrp("test").unwrap().name(), "testcoin");
+        assert_eq!(Network::from_magic(0x54455354).unwrap().name(), "testcoin");
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(Network::bitcoin(), Network::bitcoin());
+        assert_ne!(Network::bitcoin(), Network::bitcoin_testnet());
+    }
+
+    #[test]
+    fn ord() {
+        let mut sorted = all_networks();
+        sorted.sort();
+        assert!(sorted.windows(2).all(|w| w[0].name() < w[1].name()));
+        let mut sorted_again = sorted.clone();
+        sorted_again.sort();
+        assert_eq!(sorted, sorted_again);
+
+        let mut params = BTreeMap::new();
+        params.insert(Network::bitcoin(), Network::bitcoin().chain_params());
+        assert_eq!(params[&Network::bitcoin()].segwit_height, 481824);
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(Network::default(), Network::bitcoin());
+
+        #[derive(Default)]
+        struct Config {
+            network: Network,
+        }
+        assert!(Config::default().network.is_mainnet());
+    }
+
+    #[test]
+    fn hash_map_key() {
+        let mut explorers = HashMap::new();
+        explorers.insert(Network::bitcoin(), "mempool.space");
+        explorers.insert(Network::bitcoin_testnet(), "mempool.space/testnet");
+        assert_eq!(explorers[&Network::bitcoin()], "mempool.space");
+        assert_eq!(explorers.get(&Network::bitcoin_regtest()), None);
+    }
+
+    #[test]
+    fn display_name() {
+        for n in all_networks() {
+            // "Bitcoin Testnet4" is displayed by its network type only
+            let short_display_name = n.short_display_name().to_lowercase();
+            assert!(short_display_name.starts_with(&n.display_name().to_lowercase()), "{}", n.name());
+        }
+        assert_eq!(Network::bitcoin().display_name(), "Bitcoin Mainnet");
+        assert_eq!(Network::bitcoin_testnet().currency_and_type_display(), "bitcoin (testnet)");
+    }
+
+    #[test]
+    fn summary() {
+        let summary = Network::bitcoin().to_summary_string();
+        assert!(summary.contains("name: bitcoin\n"));
+        assert!(summary.contains("magic: 0xd9b4bef9\n"));
+        assert!(summary.contains("xpub prefix: 0x0488b21e\n"));
+        assert!(summary.contains("genesis block: 000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f\n"));
+    }
+
+    #[test]
+    fn all_by_currency() {
+        let currencies = Network::all_by_currency();
+        let bitcoin = &currencies["bitcoin"];
+        assert_eq!(bitcoin.len(), 5);
+        assert_eq!(bitcoin[0].network_type(), NetworkType::Mainnet);
+        for networks in currencies.values() {
+            assert!(networks.windows(2).all(|w| w[0].network_type() <= w[1].network_type()));
+        }
+    }
+
+    #[test]
+    fn network_type_helpers() {
+        assert!(Network::bitcoin().is_mainnet());
+        assert!(Network::bitcoin_testnet().is_testnet());
+        assert!(Network::bitcoin_signet().is_signet());
+        assert!(Network::bitcoin_regtest().is_regtest());
+        for n in all_networks() {
+            let flags = [n.is_mainnet(), n.is_testnet(), n.is_signet(), n.is_regtest()];
+            assert_eq!(flags.iter().filter(|&&f| f).count(), 1, "{}", n.name());
+        }
+    }
+
+    #[test]
+    fn is_test_network() {
+        assert!(Network::bitcoin().is_production_network());
+        assert!(!Network::bitcoin().is_test_network());
+        for n in Network::all_bitcoin().skip(1) {
+            assert!(n.is_test_network(), "{}", n.name());
+            assert!(!n.is_production_network(), "{}", n.name());
+        }
+        for n in all_networks() {
+            assert_ne!(n.is_test_network(), n.is_production_network());
+        }
+    }
+
+    #[test]
+    fn network_type_is_production() {
+        assert!(NetworkType::Mainnet.is_production());
+        assert!(!NetworkType::Mainnet.is_test());
+        for network_type in &[NetworkType::Testnet, NetworkType::Signet, NetworkType::Regtest] {
+            assert!(network_type.is_test());
+            assert!(!network_type.is_production());
+        }
+        for n in all_networks() {
+            assert_eq!(n.network_type().is_test(), n.is_test_network(), "{}", n.name());
+        }
+    }
+
+    #[test]
+    fn network_type_strings() {
+        assert_eq!(NetworkType::all().len(), 4);
+        assert!(NetworkType::all().windows(2).all(|w| w[0] < w[1]));
+        for network_type in NetworkType::all() {
+            let s: &'static str = (*network_type).into();
+            assert_eq!(NetworkType::try_from(s), Ok(*network_type));
+            assert_eq!(NetworkType::try_from(&*s.to_uppercase()), Ok(*network_type));
+        }
+        let signet: &str = NetworkType::Signet.into();
+        assert_eq!(signet, "signet");
+        let err = NetworkType::try_from("devnet").unwrap_err();
+        assert_eq!(err, ParseNetworkTypeError("devnet".to_owned()));
+        assert_eq!(err.to_string(), "unknown network type: 'devnet'");
+    }
+
+    #[test]
+    fn bolt11_hrp() {
+        assert_eq!(Network::bitcoin().bolt11_hrp(), "lnbc");
+        assert_eq!(Network::bitcoin_testnet().bolt11_hrp(), "lntb");
+        assert_eq!(Network::bitcoin_regtest().bolt11_hrp(), "lnbcrt");
+        assert_eq!(Network::litecoin().bolt11_hrp(), "lnltc");
+        assert_eq!(Network::dogecoin().bolt11_hrp(), "");
+    }
+
+    #[test]
+    fn address_prefixes() {
+        let prefixes = Network::bitcoin().address_prefixes();
+        assert_eq!((prefixes.p2pk, prefixes.p2pkh, prefixes.p2sh), (0, 0, 5));
+        for n in all_networks() {
+            let prefixes = n.address_prefixes();
+            assert_eq!(prefixes.p2pkh, n.p2pkh_prefix());
+            assert_eq!(prefixes.p2sh, n.p2sh_prefix());
+        }
+    }
+
+    #[test]
+    fn slip132_prefixes() {
+        let bitcoin = Network::bitcoin();
+        assert_eq!(bitcoin.ypub_prefix(), &[0x04, 0x9D, 0x7C, 0xB2]);
+        assert_eq!(bitcoin.ypriv_prefix(), &[0x04, 0x9D, 0x78, 0x78]);
+        assert_eq!(bitcoin.zpub_prefix(), &[0x04, 0xB2, 0x47, 0x46]);
+        assert_eq!(bitcoin.zpriv_prefix(), &[0x04, 0xB2, 0x43, 0x0C]);
+        assert_eq!(bitcoin.ypub_multisig_prefix(), &[0x02, 0x95, 0xB4, 0x3F]);
+        assert_eq!(bitcoin.ypriv_multisig_prefix(), &[0x02, 0x95, 0xB0, 0x05]);
+        assert_eq!(bitcoin.zpub_multisig_prefix(), &[0x02, 0xAA, 0x7E, 0xD3]);
+        assert_eq!(bitcoin.zpriv_multisig_prefix(), &[0x02, 0xAA, 0x7A, 0x99]);
+        for testnet in vec![Network::bitcoin_testnet(), Network::bitcoin_regtest()] {
+            assert_eq!(testnet.ypub_prefix(), &[0x04, 0x4A, 0x52, 0x62]);
+            assert_eq!(testnet.ypriv_prefix(), &[0x04, 0x4A, 0x4E, 0x28]);
+            assert_eq!(testnet.zpub_prefix(), &[0x04, 0x5F, 0x1C, 0xF6]);
+            assert_eq!(testnet.zpriv_prefix(), &[0x04, 0x5F, 0x18, 0xBC]);
+            assert_eq!(testnet.ypub_multisig_prefix(), &[0x02, 0x42, 0x89, 0xEF]);
+            assert_eq!(testnet.ypriv_multisig_prefix(), &[0x02, 0x42, 0x85, 0xB5]);
+            assert_eq!(testnet.zpub_multisig_prefix(), &[0x02, 0x57, 0x54, 0x83]);
+            assert_eq!(testnet.zpriv_multisig_prefix(), &[0x02, 0x57, 0x50, 0x48]);
+        }
+
+        let prefixes = bitcoin.extended_key_prefixes();
+        assert_eq!(prefixes.xpub, bitcoin.xpub_prefix());
+        assert_eq!(prefixes.zpub, &[0x04, 0xB2, 0x47, 0x46]);
+        assert_eq!(prefixes.zpriv_multisig, &[0x02, 0xAA, 0x7A, 0x99]);
+        assert_ne!(prefixes, Network::bitcoin_testnet().extended_key_prefixes());
+    }
+
+    #[test]
+    fn slip44_coin_type() {
+        assert_eq!(Network::bitcoin().slip44_coin_type(), 0);
+        assert_eq!(Network::bitcoin_testnet().slip44_coin_type(), 1);
+        assert_eq!(Network::litecoin().slip44_coin_type(), 2);
+        assert_eq!(Network::dogecoin().slip44_coin_type(), 3);
+    }
+
+    #[test]
+    fn unique_bitcoin_ports() {
+        let bitcoin = &Network::all_by_currency()["bitcoin"];
+        for (i, a) in bitcoin.iter().enumerate() {
+            for b in &bitcoin[i + 1..] {
+                assert_ne!(a.default_port(), b.default_port());
+            }
+        }
+    }
+
+    #[test]
+    fn taproot_height() {
+        assert_eq!(Network::bitcoin().chain_params().taproot_height, 709632);
+        for n in all_networks() {
+            if n.chain_params().taproot_height != u32::MAX {
+                assert!(n.supports_taproot(), "{}", n.name());
+            }
+        }
+    }
+
+    #[test]
+    fn difficulty_adjustment_interval() {
+        for n in vec![Network::bitcoin(), Network::bitcoin_testnet(), Network::bitcoin_regtest()] {
+            assert_eq!(n.chain_params().difficulty_adjustment_interval(), Some(2016));
+        }
+        assert_eq!(Network::dogecoin().chain_params().difficulty_adjustment_interval(), Some(4));
+        let mut params = Network::bitcoin().chain_params();
+        params.pow_target_spacing = 0;
+        assert_eq!(params.difficulty_adjustment_interval(), None);
+    }
+
+    #[test]
+    fn expected_blocks() {
+        for n in Network::all_bitcoin() {
+            let params = n.chain_params();
+            assert_eq!(params.retarget_interval_seconds(), 1_209_600, "{}", n.name());
+            assert_eq!(params.expected_blocks_per_day(), Some(144), "{}", n.name());
+            assert_eq!(params.expected_blocks_per_year(), Some(52560), "{}", n.name());
+        }
+        assert_eq!(Network::dogecoin().chain_params().expected_blocks_per_day(), Some(1440));
+        assert_eq!(Network::litecoin().chain_params().expected_blocks_per_day(), Some(576));
+        let mut params = Network::bitcoin().chain_params();
+        params.pow_target_spacing = 0;
+        assert_eq!(params.expected_blocks_per_day(), None);
+        assert_eq!(params.expected_blocks_per_year(), None);
+    }
+
+    #[test]
+    fn halving_heights() {
+        let n = Network::bitcoin();
+        let params = n.chain_params();
+        let subsidy = n.initial_block_subsidy_satoshis();
+        let heights: Vec<u32> = params.halving_heights(subsidy, n.halving_interval()).collect();
+        assert_eq!(heights[..3], [210_000, 420_000, 630_000]);
+        assert_eq!(heights.len(), 33);
+        assert_eq!(heights.last(), Some(&6_930_000));
+        assert_eq!(n.block_subsidy_at_height(6_930_000 - 1), 1);
+        assert_eq!(n.block_subsidy_at_height(6_930_000), 0);
+
+        assert_eq!(params.halving_heights(0, 210_000).count(), 0);
+        assert_eq!(params.halving_heights(50, 0).count(), 0);
+        assert_eq!(params.halving_heights(u64::MAX, u32::MAX).count(), 0);
+        let heights: Vec<u32> = params.halving_heights(u64::MAX, u32::MAX / 2).collect();
+        assert_eq!(heights, vec![u32::MAX / 2, u32::MAX - 1]);
+    }
+
+    #[test]
+    fn total_supply_at_height() {
+        let n = Network::bitcoin();
+        let params = n.chain_params();
+        let supply = |height| params.total_supply_at_height(height, 50 * 100_000_000, 210_000);
+        assert_eq!(supply(0), 5_000_000_000);
+        assert_eq!(supply(209_999), 1_050_000_000_000_000);
+        assert_eq!(supply(210_000), 1_050_002_500_000_000);
+        assert_eq!(supply(420_000), 1_575_001_250_000_000);
+        assert_eq!(supply(21_000_000), 2_099_999_997_690_000);
+        assert_eq!(supply(u32::MAX), supply(21_000_000));
+        assert!(supply(21_000_000) < n.max_money());
+
+        assert_eq!(params.total_supply_at_height(9, 100, u32::MAX), 1000);
+        assert_eq!(params.total_supply_at_height(u32::MAX, u64::MAX, 0), u64::MAX);
+    }
+
+    #[test]
+    fn bip_activation() {
+        for n in vec![Network::bitcoin(), Network::bitcoin_testnet(), Network::bitcoin_regtest()] {
+            let params = n.chain_params();
+            assert!(!params.is_bip34_active(params.bip34_height - 1), "{}", n.name());
+            assert!(params.is_bip34_active(params.bip34_height), "{}", n.name());
+            assert!(!params.is_bip65_active(params.bip65_height - 1), "{}", n.name());
+            assert!(params.is_bip65_active(params.bip65_height), "{}", n.name());
+            assert!(!params.is_bip66_active(params.bip66_height - 1), "{}", n.name());
+            assert!(params.is_bip66_active(params.bip66_height), "{}", n.name());
+        }
+
+        let params = Network::bitcoin().chain_params();
+        assert!(!params.is_bip34_active(227930));
+        assert!(params.is_bip34_active(227931));
+        assert!(params.is_bip65_active(388381));
+        assert!(!params.is_bip66_active(363724));
+        assert!(!Network::bitcoin_regtest().chain_params().is_bip34_active(1_000_000));
+    }
+
+    #[test]
+    fn chain_params_eq() {
+        assert_eq!(Network::bitcoin().chain_params(), Network::bitcoin().chain_params());
+        assert_ne!(Network::bitcoin_testnet().chain_params(), Network::bitcoin().chain_params());
+    }
+
+    #[test]
+    fn validate_chain_params() {
+        for n in all_networks() {
+            assert_eq!(n.chain_params().validate(), Ok(()), "{}", n.name());
+        }
+
+        let valid = Network::bitcoin().chain_params();
+        let mut params = valid.clone();
+        params.pow_target_spacing = 0;
+        assert_eq!(params.validate(), Err(ChainParamsError::ZeroPowTargetSpacing));
+        let mut params = valid.clone();
+        params.pow_target_timespan = params.pow_target_spacing - 1;
+        assert_eq!(params.validate(), Err(ChainParamsError::PowTargetTimespanBelowSpacing));
+        let mut params = valid.clone();
+        params.miner_confirmation_window = 0;
+        assert_eq!(params.validate(), Err(ChainParamsError::ZeroMinerConfirmationWindow));
+        let mut params = valid.clone();
+        params.rule_change_activation_threshold = params.miner_confirmation_window + 1;
+        assert_eq!(params.validate(), Err(ChainParamsError::ActivationThresholdAboveWindow));
+        let mut params = valid;
+        params.pow_limit = [0; 4];
+        assert_eq!(params.validate(), Err(ChainParamsError::ZeroPowLimit));
+    }
+
+    #[test]
+    fn witness_scale_factor() {
+        for n in all_networks() {
+            let params = n.chain_params();
+            if params.segwit_height != u32::MAX {
+                assert_eq!(params.witness_scale_factor, 4, "{}", n.name());
+            }
+        }
+        let no_segwit = vec![
+            Network::dogecoin(),
+            Network::dogecoin_testnet(),
+            Network::ravencoin(),
+            Network::ravencoin_testnet(),
+        ];
+        for n in no_segwit {
+            let params = n.chain_params();
+            assert_eq!(params.witness_scale_factor, 1, "{}", n.name());
+            assert_eq!(params.max_block_weight, 4 * params.max_block_size, "{}", n.name());
+        }
+    }
+
+    #[test]
+    fn relay_policy() {
+        for n in vec![Network::bitcoin(), Network::bitcoin_testnet(), Network::bitcoin_regtest()] {
+            let params = n.chain_params();
+            assert_eq!(params.dust_limit_satoshis, 546);
+            assert_eq!(params.min_relay_tx_fee_rate, 1000);
+        }
+    }
+
+    #[test]
+    fn bip68_height() {
+        for n in all_networks() {
+            let params = n.chain_params();
+            assert_eq!(params.bip68_height, params.csv_height, "{}", n.name());
+        }
+        assert_eq!(Network::bitcoin().chain_params().bip68_height, 419328);
+        assert_eq!(Network::bitcoin_testnet().chain_params().bip68_height, 770112);
+        let untracked = Network::feathercoin().chain_params();
+        assert_eq!(untracked.csv_height, u32::MAX);
+        assert!(!untracked.is_bip34_active(1_000_000));
+    }
+
+    #[test]
+    fn bip9_deployments() {
+        let deployments = Network::bitcoin().bip9_deployments();
+        let names: Vec<&str> = deployments.iter().map(|d| d.name).collect();
+        assert_eq!(names, vec!["csv", "segwit", "taproot"]);
+        assert_eq!(
+            deployments[1],
+            Bip9Deployment {
+                name: "segwit",
+                bit: 1,
+                start_time: 1479168000,
+                timeout: 1510704000,
+                min_activation_height: 0,
+            }
+        );
+        let taproot_height = Network::bitcoin().chain_params().taproot_height;
+        assert_eq!(deployments[2].min_activation_height, taproot_height);
+        assert_eq!(Network::bitcoin_testnet().bip9_deployments().len(), 3);
+        assert!(Network::bitcoin_regtest().bip9_deployments().is_empty());
+        for n in all_networks() {
+            assert!(n.bip9_deployments().iter().all(|d| d.bit < 29 && d.start_time < d.timeout));
+        }
+    }
+
+    #[test]
+    fn block_limits() {
+        for n in all_networks() {
+            let params = n.chain_params();
+            let sigops = 20_000 * params.witness_scale_factor;
+            assert_eq!(params.max_block_sigops, sigops, "{}", n.name());
+            assert_eq!(params.max_block_size, 1_000_000, "{}", n.name());
+            assert!(params.max_block_weight >= params.max_block_size, "{}", n.name());
+        }
+    }
+
+    fn assert_same_constants(a: &dyn NetworkConstants, b: &dyn NetworkConstants) {
+        let target = PowTarget(b.chain_params().pow_limit);
+        assert_eq!(a.hrp(), b.hrp());
+        assert_eq!(a.bolt11_hrp(), b.bolt11_hrp());
+        assert_eq!(a.p2pk_prefix(), b.p2pk_prefix());
+        assert_eq!(a.p2pkh_prefix(), b.p2pkh_prefix());
+        assert_eq!(a.p2sh_prefix(), b.p2sh_prefix());
+        assert_eq!(a.xpub_prefix(), b.xpub_prefix());
+        assert_eq!(a.xpriv_prefix(), b.xpriv_prefix());
+        assert_eq!(a.ypub_prefix(), b.ypub_prefix());
+        assert_eq!(a.ypriv_prefix(), b.ypriv_prefix());
+        assert_eq!(a.zpub_prefix(), b.zpub_prefix());
+        assert_eq!(a.zpriv_prefix(), b.zpriv_prefix());
+        assert_eq!(a.ypub_multisig_prefix(), b.ypub_multisig_prefix());
+        assert_eq!(a.ypriv_multisig_prefix(), b.ypriv_multisig_prefix());
+        assert_eq!(a.zpub_multisig_prefix(), b.zpub_multisig_prefix());
+        assert_eq!(a.zpriv_multisig_prefix(), b.zpriv_multisig_prefix());
+        assert_eq!(a.wif_prefix(), b.wif_prefix());
+        assert_eq!(a.magic(), b.magic());
+        assert_eq!(a.name(), b.name());
+        assert_eq!(a.currency_name(), b.currency_name());
+        assert_eq!(a.short_display_name(), b.short_display_name());
+        assert_eq!(a.ticker(), b.ticker());
+        assert_eq!(a.default_datadir_name_unix(), b.default_datadir_name_unix());
+        assert_eq!(a.default_datadir_name_windows(), b.default_datadir_name_windows());
+        assert_eq!(a.network_subdir_name(), b.network_subdir_name());
+        assert_eq!(a.network_type(), b.network_type());
+        assert_eq!(a.chain_params(), b.chain_params());
+        assert_eq!(a.bip9_deployments(), b.bip9_deployments());
+        assert_eq!(a.genesis_block(), b.genesis_block());
+        assert_eq!(a.genesis_block_timestamp(), b.genesis_block_timestamp());
+        assert_eq!(a.genesis_block_nonce(), b.genesis_block_nonce());
+        assert_eq!(a.genesis_block_bits(), b.genesis_block_bits());
+        assert_eq!(a.genesis_block_version(), b.genesis_block_version());
+        assert_eq!(a.genesis_merkle_root(), b.genesis_merkle_root());
+        assert_eq!(
+            a.genesis_block_coinbase_value_satoshis(),
+            b.genesis_block_coinbase_value_satoshis()
+        );
+        assert_eq!(a.genesis_coinbase_is_spendable(), b.genesis_coinbase_is_spendable());
+        assert_eq!(a.minimum_chain_work_hex(), b.minimum_chain_work_hex());
+        assert_eq!(a.supports_taproot(), b.supports_taproot());
+        assert_eq!(a.halving_interval(), b.halving_interval());
+        assert_eq!(a.slip44_coin_type(), b.slip44_coin_type());
+        assert_eq!(a.default_port(), b.default_port());
+        assert_eq!(a.rpc_port(), b.rpc_port());
+        assert_eq!(a.coinbase_maturity(), b.coinbase_maturity());
+        assert_eq!(a.max_money(), b.max_money());
+        assert_eq!(a.initial_block_subsidy_satoshis(), b.initial_block_subsidy_satoshis());
+        assert_eq!(a.clone_boxed().name(), b.clone_boxed().name());
+        assert_eq!(a.satoshis_per_bitcoin(), b.satoshis_per_bitcoin());
+        assert_eq!(a.op_return_output_value(), b.op_return_output_value());
+        assert_eq!(a.max_op_return_size_bytes(), b.max_op_return_size_bytes());
+        assert_eq!(a.is_valid_op_return_output(1000, 40), b.is_valid_op_return_output(1000, 40));
+        assert_eq!(a.p2ms_max_keys_standard_output(), b.p2ms_max_keys_standard_output());
+        assert_eq!(a.p2sh_max_sigops_standard(), b.p2sh_max_sigops_standard());
+        assert_eq!(a.is_standard_p2ms_output(2, 2), b.is_standard_p2ms_output(2, 2));
+        assert_eq!(a.sequence_final(), b.sequence_final());
+        assert_eq!(a.sequence_enable_locktime(), b.sequence_enable_locktime());
+        assert_eq!(a.sequence_bip125_rbf_max(), b.sequence_bip125_rbf_max());
+        assert_eq!(a.sequence_opt_in_rbf_max(), b.sequence_opt_in_rbf_max());
+        assert_eq!(a.is_rbf_signal(210000), b.is_rbf_signal(210000));
+        assert_eq!(a.bip125_opt_in_rbf_max_sequence(), b.bip125_opt_in_rbf_max_sequence());
+        assert_eq!(
+            a.bip125_replacement_fee_increment_sat_per_kvb(),
+            b.bip125_replacement_fee_increment_sat_per_kvb()
+        );
+        assert_eq!(a.bip125_is_opt_in_rbf(210000), b.bip125_is_opt_in_rbf(210000));
+        assert_eq!(
+            a.bip125_replacement_min_fee_bump_satoshis(1000, 1000),
+            b.bip125_replacement_min_fee_bump_satoshis(1000, 1000)
+        );
+        assert_eq!(a.blocks_per_era(), b.blocks_per_era());
+        assert_eq!(a.era_at_height(210000), b.era_at_height(210000));
+        assert_eq!(a.first_height_of_era(210000), b.first_height_of_era(210000));
+        assert_eq!(a.last_height_of_era(210000), b.last_height_of_era(210000));
+        assert_eq!(a.block_subsidy_at_height(210000), b.block_subsidy_at_height(210000));
+        assert_eq!(a.max_addr_message_entries(), b.max_addr_message_entries());
+        assert_eq!(a.max_addrv2_message_entries(), b.max_addrv2_message_entries());
+        assert_eq!(a.max_outbound_connections_default(), b.max_outbound_connections_default());
+        assert_eq!(a.max_inbound_connections_default(), b.max_inbound_connections_default());
+        assert_eq!(a.max_feeler_connections(), b.max_feeler_connections());
+        assert_eq!(a.getdata_block_timeout_seconds(), b.getdata_block_timeout_seconds());
+        assert_eq!(a.getdata_tx_timeout_seconds(), b.getdata_tx_timeout_seconds());
+        assert_eq!(a.ping_interval_seconds(), b.ping_interval_seconds());
+        assert_eq!(a.ping_timeout_seconds(), b.ping_timeout_seconds());
+        assert_eq!(a.connection_timeout_seconds(), b.connection_timeout_seconds());
+        assert_eq!(a.minimum_chain_work_bytes(), b.minimum_chain_work_bytes());
+        assert_eq!(a.bip47_version_byte(), b.bip47_version_byte());
+        assert_eq!(a.bip47_payment_code_bytes(), b.bip47_payment_code_bytes());
+        assert_eq!(a.bip47_feature_bit_bitmask(), b.bip47_feature_bit_bitmask());
+        assert_eq!(
+            a.bip47_x_coordinate_is_valid(&[2; 32]),
+            b.bip47_x_coordinate_is_valid(&[2; 32])
+        );
+        assert_eq!(a.finality_confirmations(), b.finality_confirmations());
+        assert_eq!(a.small_amount_confirmations(), b.small_amount_confirmations());
+        assert_eq!(a.is_tx_final(210000), b.is_tx_final(210000));
+        assert_eq!(
+            a.confirmations_equivalent_to_bitcoin_6(),
+            b.confirmations_equivalent_to_bitcoin_6()
+        );
+        assert_eq!(a.discourages_address_reuse(), b.discourages_address_reuse());
+        assert_eq!(a.address_reuse_warning_message(), b.address_reuse_warning_message());
+        assert_eq!(a.supported_descriptor_types(), b.supported_descriptor_types());
+        assert_eq!(
+            a.descriptor_type_is_supported(DescriptorType::Wpkh),
+            b.descriptor_type_is_supported(DescriptorType::Wpkh)
+        );
+        assert_eq!(a.musig2_supported(), b.musig2_supported());
+        assert_eq!(a.musig2_nonce_size_bytes(), b.musig2_nonce_size_bytes());
+        assert_eq!(
+            a.musig2_partial_signature_size_bytes(),
+            b.musig2_partial_signature_size_bytes()
+        );
+        assert_eq!(a.bloom_filter_max_hash_funcs(), b.bloom_filter_max_hash_funcs());
+        assert_eq!(a.bloom_filter_nflags_none(), b.bloom_filter_nflags_none());
+        assert_eq!(a.bloom_filter_nflags_all(), b.bloom_filter_nflags_all());
+        assert_eq!(a.bloom_filter_nflags_p2pubkey_only(), b.bloom_filter_nflags_p2pubkey_only());
+        assert_eq!(a.bloom_filter_max_size_bytes(), b.bloom_filter_max_size_bytes());
+        assert_eq!(a.compact_size_1_byte_max(), b.compact_size_1_byte_max());
+        assert_eq!(a.compact_size_3_byte_marker(), b.compact_size_3_byte_marker());
+        assert_eq!(a.compact_size_5_byte_marker(), b.compact_size_5_byte_marker());
+        assert_eq!(a.compact_size_9_byte_marker(), b.compact_size_9_byte_marker());
+        assert_eq!(a.compact_size_encoded_length(1000), b.compact_size_encoded_length(1000));
+        assert_eq!(
+            a.precomputed_sha256_midstate_for_version_1_inputs(),
+            b.precomputed_sha256_midstate_for_version_1_inputs()
+        );
+        assert_eq!(
+            a.taproot_sighash_sha256_initial_state(),
+            b.taproot_sighash_sha256_initial_state()
+        );
+        assert_eq!(a.segwit_commitment_header(), b.segwit_commitment_header());
+        assert_eq!(a.segwit_commitment_total_size(), b.segwit_commitment_total_size());
+        assert_eq!(a.segwit_witness_reserved_value(), b.segwit_witness_reserved_value());
+        assert_eq!(
+            a.segwit_commitment_is_present(&[0x6a, 0x24]),
+            b.segwit_commitment_is_present(&[0x6a, 0x24])
+        );
+        assert_eq!(a.bip158_filter_supported(), b.bip158_filter_supported());
+        assert_eq!(a.bip158_filter_type_basic(), b.bip158_filter_type_basic());
+        assert_eq!(a.bip158_filter_parameter_m(), b.bip158_filter_parameter_m());
+        assert_eq!(a.bip158_filter_parameter_p(), b.bip158_filter_parameter_p());
+        assert_eq!(a.bip158_siphash_key_prefix(), b.bip158_siphash_key_prefix());
+        assert_eq!(a.expected_hashes_per_block(&target), b.expected_hashes_per_block(&target));
+        assert_eq!(
+            a.expected_hashrate_for_block_time_hashes_per_second(&target),
+            b.expected_hashrate_for_block_time_hashes_per_second(&target)
+        );
+        assert_eq!(a.is_mainnet(), b.is_mainnet());
+        assert_eq!(a.is_testnet(), b.is_testnet());
+        assert_eq!(a.is_signet(), b.is_signet());
+        assert_eq!(a.is_regtest(), b.is_regtest());
+        assert_eq!(a.is_test_network(), b.is_test_network());
+        assert_eq!(a.is_production_network(), b.is_production_network());
+        assert_eq!(a.extended_key_prefixes(), b.extended_key_prefixes());
+        assert_eq!(a.address_prefixes(), b.address_prefixes());
+        assert_eq!(a.genesis_block_header(), b.genesis_block_header());
+        assert_eq!(a.challenge_script(), b.challenge_script());
+        assert_eq!(a.signet_challenge(), b.signet_challenge());
+    }
+
+    #[test]
+    fn pointer_impls() {
+        let arc = Arc::new(networks::BITCOIN);
+        assert_same_constants(&arc, &networks::BITCOIN);
+        assert_same_constants(&&networks::BITCOIN, &networks::BITCOIN);
+        let signet: Arc<dyn NetworkConstants> = Arc::new(networks::BITCOIN_SIGNET);
+        assert_same_constants(&signet, &networks::BITCOIN_SIGNET);
+        // provided methods overridden by the pointee are delegated too
+        assert_eq!(signet.challenge_script(), Some(&networks::DEFAULT_SIGNET_CHALLENGE[..]));
+
+        let builder = NetworkBuilder::new().name("testcoin").hrp("test").magic(1);
+        let shared = Arc::new(builder.build().unwrap());
+        let handle = {
+            let shared = shared.clone();
+            ::std::thread::spawn(move || shared.hrp())
+        };
+        assert_eq!(handle.join().unwrap(), "test");
+        assert_eq!(Network::from_box(Box::new(shared)).name(), "testcoin");
+    }
+
+    #[test]
+    fn dont_panic() {
+        for n in Network::iter() {
+            let _ = n.hrp();
+            let _ = n.bolt11_hrp();
+            let _ = n.p2pk_prefix();
+            let _ = n.p2pkh_prefix();
+            let _ = n.p2sh_prefix();
+            let _ = n.xpub_prefix();
+            let _ = n.xpriv_prefix();
+            let _ = n.ypub_prefix();
+            let _ = n.ypriv_prefix();
+            let _ = n.zpub_prefix();
+            let _ = n.zpriv_prefix();
+            let _ = n.ypub_multisig_prefix();
+            let _ = n.ypriv_multisig_prefix();
+            let _ = n.zpub_multisig_prefix();
+            let _ = n.zpriv_multisig_prefix();
+            let _ = n.wif_prefix();
+            let _ = n.magic();
+            let _ = n.name();
+            let _ = n.currency_name();
+            let _ = n.short_display_name();
+            let _ = n.ticker();
+            let _ = n.default_datadir_name_unix();
+            let _ = n.default_datadir_name_windows();
+            let _ = n.network_subdir_name();
+            let _ = n.network_type();
+            let _ = n.chain_params();
+            let _ = n.bip9_deployments();
+            let _ = n.genesis_block();
+            let _ = n.genesis_block_timestamp();
+            let _ = n.genesis_block_nonce();
+            let _ = n.genesis_block_bits();
+            let _ = n.genesis_block_version();
+            let _ = n.genesis_merkle_root();
+            let _ = n.genesis_block_coinbase_value_satoshis();
+            let _ = n.genesis_coinbase_is_spendable();
+            let _ = n.minimum_chain_work_hex();
+            let _ = n.assumed_valid_block();
+            let _ = n.supports_taproot();
+            let _ = n.halving_interval();
+            let _ = n.slip44_coin_type();
+            let _ = n.default_port();
+            let _ = n.rpc_port();
+            let _ = n.coinbase_maturity();
+            let _ = n.max_money();
+            let _ = n.initial_block_subsidy_satoshis();
+            let _ = n.clone_boxed();
+            let _ = n.blocks_per_era();
+            let _ = n.era_at_height(0);
+            let _ = n.first_height_of_era(0);
+            let _ = n.block_subsidy_at_height(u32::MAX);
+            let _ = n.last_height_of_era(0);
+            let _ = n.minimum_chain_work_bytes();
+            let _ = n.satoshis_per_bitcoin();
+            let _ = n.op_return_output_value();
+            let _ = n.max_op_return_size_bytes();
+            let _ = n.is_valid_op_return_output(0, 0);
+            let _ = n.p2ms_max_keys_standard_output();
+            let _ = n.p2sh_max_sigops_standard();
+            let _ = n.is_standard_p2ms_output(1, 1);
+            let _ = n.sequence_final();
+            let _ = n.sequence_enable_locktime();
+            let _ = n.sequence_bip125_rbf_max();
+            let _ = n.sequence_opt_in_rbf_max();
+            let _ = n.is_rbf_signal(0);
+            let _ = n.bip125_opt_in_rbf_max_sequence();
+            let _ = n.bip125_replacement_fee_increment_sat_per_kvb();
+            let _ = n.bip125_is_opt_in_rbf(0);
+            let _ = n.bip125_replacement_min_fee_bump_satoshis(0, 0);
+            let _ = n.max_addr_message_entries();
+            let _ = n.max_addrv2_message_entries();
+            let _ = n.max_outbound_connections_default();
+            let _ = n.max_inbound_connections_default();
+            let _ = n.max_feeler_connections();
+            let _ = n.getdata_block_timeout_seconds();
+            let _ = n.getdata_tx_timeout_seconds();
+            let _ = n.ping_interval_seconds();
+            let _ = n.ping_timeout_seconds();
+            let _ = n.connection_timeout_seconds();
+            let _ = n.bip47_version_byte();
+            let _ = n.bip47_payment_code_bytes();
+            let _ = n.bip47_feature_bit_bitmask();
+            let _ = n.bip47_x_coordinate_is_valid(&[0; 32]);
+            let _ = n.finality_confirmations();
+            let _ = n.small_amount_confirmations();
+            let _ = n.is_tx_final(0);
+            let _ = n.confirmations_equivalent_to_bitcoin_6();
+            let _ = n.discourages_address_reuse();
+            let _ = n.address_reuse_warning_message();
+            let _ = n.supported_descriptor_types();
+            let _ = n.descriptor_type_is_supported(DescriptorType::Tr);
+            let _ = n.musig2_supported();
+            let _ = n.musig2_nonce_size_bytes();
+            let _ = n.musig2_partial_signature_size_bytes();
+            let _ = n.bloom_filter_max_hash_funcs();
+            let _ = n.bloom_filter_nflags_none();
+            let _ = n.bloom_filter_nflags_all();
+            let _ = n.bloom_filter_nflags_p2pubkey_only();
+            let _ = n.bloom_filter_max_size_bytes();
+            let _ = n.compact_size_1_byte_max();
+            let _ = n.compact_size_3_byte_marker();
+            let _ = n.compact_size_5_byte_marker();
+            let _ = n.compact_size_9_byte_marker();
+            let _ = n.compact_size_encoded_length(0);
+            let _ = n.precomputed_sha256_midstate_for_version_1_inputs();
+            let _ = n.taproot_sighash_sha256_initial_state();
+            let _ = n.segwit_commitment_header();
+            let _ = n.segwit_commitment_total_size();
+            let _ = n.segwit_witness_reserved_value();
+            let _ = n.segwit_commitment_is_present(&[]);
+            let _ = n.bip158_filter_supported();
+            let _ = n.bip158_filter_type_basic();
+            let _ = n.bip158_filter_parameter_m();
+            let _ = n.bip158_filter_parameter_p();
+            let _ = n.bip158_siphash_key_prefix();
+            let _ = n.expected_hashes_per_block(&PowTarget(n.chain_params().pow_limit));
+            let _ = n.is_mainnet();
+            let _ = n.is_testnet();
+            let _ = n.is_signet();
+            let _ = n.is_regtest();
+            let _ = n.is_test_network();
+            let _ = n.is_production_network();
+            let _ = n.extended_key_prefixes();
+            let _ = n.address_prefixes();
+            let _ = n.genesis_block_header();
+            let _ = n.challenge_script();
+            let _ = n.signet_challenge();
+            let _ = n.expected_hashrate_for_block_time_hashes_per_second(&PowTarget(n.chain_params().pow_limit));
+        }
+    }
+
+    #[test]
+    fn minimum_chain_work() {
+        for n in all_networks() {
+            assert_eq!(n.minimum_chain_work_hex().len(), 64);
+            assert!(n.minimum_chain_work_bytes().is_some(), "{}", n.name());
+        }
+        assert_eq!(Network::bitcoin_regtest().minimum_chain_work_bytes(), Some([0u8; 32]));
+        for n in vec![Network::bitcoin_testnet4(), Network::bitcoin_signet()] {
+            assert_ne!(n.minimum_chain_work_bytes(), Some([0u8; 32]), "{}", n.name());
+        }
+
+        let builder = NetworkBuilder::new().name("testcoin").hrp("test").magic(0x54455354);
+        let short = builder.clone().minimum_chain_work_hex("0x00").build().unwrap();
+        assert_eq!(short.minimum_chain_work_bytes(), Some([0u8; 32]));
+        let short = builder.minimum_chain_work_hex("0x1Ab").build().unwrap();
+        assert_eq!(short.minimum_chain_work_bytes().unwrap()[30..], [0x01, 0xab]);
+
+        let work = Network::bitcoin().minimum_chain_work_bytes().unwrap();
+        assert_eq!(work[..20], [0u8; 20]);
+        assert_eq!(work[20..], [0x52, 0xb2, 0x55, 0x93, 0x53, 0xdf, 0x41, 0x17, 0xb7, 0x34, 0x8b, 0x64]);
+    }
+
+    #[test]
+    fn halving_interval() {
+        assert_eq!(Network::bitcoin().halving_interval(), 210_000);
+        assert_eq!(Network::litecoin().halving_interval(), 840_000);
+        assert_eq!(Network::dogecoin().halving_interval(), 100_000);
+        for n in vec![Network::dogecoin(), Network::dogecoin_testnet()] {
+            assert_eq!(n.initial_block_subsidy_satoshis() / 100_000_000, 1_000_000);
+        }
+
+        let n = Network::groestlcoin();
+        assert_eq!(n.halving_interval(), u32::MAX);
+        assert_eq!(n.era_at_height(5_000_000), 0);
+        assert_eq!(n.last_height_of_era(0), u32::MAX - 1);
+    }
+
+    #[test]
+    fn halving_eras() {
+        let n = Network::bitcoin();
+        assert_eq!(n.blocks_per_era(), n.halving_interval());
+        assert_eq!(n.era_at_height(0), 0);
+        assert_eq!(n.era_at_height(209_999), 0);
+        assert_eq!(n.era_at_height(210_000), 1);
+        assert_eq!(n.first_height_of_era(2), 420_000);
+        assert_eq!(n.last_height_of_era(0), 209_999);
+        assert_eq!(n.last_height_of_era(1), 419_999);
+    }
+
+    #[test]
+    fn block_subsidy_at_height() {
+        let n = Network::bitcoin();
+        assert_eq!(n.block_subsidy_at_height(0), 5_000_000_000);
+        assert_eq!(n.block_subsidy_at_height(209_999), 5_000_000_000);
+        assert_eq!(n.block_subsidy_at_height(210_000), 2_500_000_000);
+        assert_eq!(n.block_subsidy_at_height(420_000), 1_250_000_000);
+        assert_eq!(n.block_subsidy_at_height(840_000), 312_500_000);
+        assert_eq!(n.block_subsidy_at_height(6_929_999), 1);
+        assert_eq!(n.block_subsidy_at_height(6_930_000), 0);
+        assert_eq!(n.block_subsidy_at_height(u32::MAX), 0);
+        assert_eq!(Network::bitcoin_regtest().block_subsidy_at_height(150), 2_500_000_000);
+        let never_halves = Network::groestlcoin();
+        let initial_subsidy = never_halves.initial_block_subsidy_satoshis();
+        assert_eq!(never_halves.block_subsidy_at_height(u32::MAX), initial_subsidy);
+        let zero_interval = NetworkBuilder::new()
+            .name("testcoin")
+            .hrp("test")
+            .magic(0x54455354)
+            .halving_interval(0)
+            .build()
+            .unwrap();
+        assert_eq!(zero_interval.era_at_height(u32::MAX), 0);
+        let initial_subsidy = zero_interval.initial_block_subsidy_satoshis();
+        assert_eq!(zero_interval.block_subsidy_at_height(u32::MAX), initial_subsidy);
+    }
+
+    #[test]
+    fn amount_conversion() {
+        let n = Network::bitcoin();
+        assert_eq!(n.btc_to_satoshis(0.0), 0);
+        assert_eq!(n.btc_to_satoshis(0.00000001), 1);
+        assert_eq!(n.btc_to_satoshis(0.000000005), 1);
+        assert_eq!(n.btc_to_satoshis(0.000000004), 0);
+        assert_eq!(n.btc_to_satoshis(0.29), 29_000_000);
+        assert_eq!(n.btc_to_satoshis(-1.0), 0);
+        assert_eq!(n.btc_to_satoshis(21_000_000.0), 2_100_000_000_000_000);
+        assert_eq!(n.satoshis_to_btc(2_100_000_000_000_000), 21_000_000.0);
+        assert_eq!(n.satoshis_to_btc(1), 0.00000001);
+
+        assert_eq!(n.format_amount(100_000), "0.00100000 BTC");
+        assert_eq!(n.format_amount(2_100_000_000_000_000), "21000000.00000000 BTC");
+    }
+
+    #[test]
+    fn op_return_output() {
+        let n = Network::bitcoin();
+        assert!(n.is_valid_op_return_output(0, 82));
+        assert!(!n.is_valid_op_return_output(0, 83));
+        assert!(!n.is_valid_op_return_output(1, 10));
+    }
+
+    #[test]
+    fn standard_p2ms_output() {
+        let n = Network::bitcoin();
+        assert!(n.is_standard_p2ms_output(1, 1));
+        assert!(n.is_standard_p2ms_output(2, 3));
+        assert!(!n.is_standard_p2ms_output(0, 3));
+        assert!(!n.is_standard_p2ms_output(3, 2));
+        assert!(!n.is_standard_p2ms_output(2, 4));
+    }
+
+    #[test]
+    fn rbf_signal() {
+        let n = Network::bitcoin();
+        assert!(n.is_rbf_signal(0));
+        assert!(n.is_rbf_signal(0xFFFFFFFD));
+        assert!(!n.is_rbf_signal(n.sequence_enable_locktime()));
+        assert!(!n.is_rbf_signal(n.sequence_final()));
+        assert!(n.bip125_is_opt_in_rbf(0xFFFFFFFD));
+        assert!(!n.bip125_is_opt_in_rbf(0xFFFFFFFE));
+        assert_eq!(n.bip125_replacement_min_fee_bump_satoshis(5_000, 2), 7_000);
+    }
+
+    #[test]
+    fn finality() {
+        let n = Network::bitcoin();
+        assert!(!n.is_tx_final(5));
+        assert!(n.is_tx_final(6));
+        assert_eq!(n.confirmations_equivalent_to_bitcoin_6(), 6);
+        assert_eq!(Network::dogecoin().confirmations_equivalent_to_bitcoin_6(), 60);
+        let mut params = Network::bitcoin_regtest().chain_params();
+        params.pow_target_spacing = 0;
+        let zero_spacing = NetworkBuilder::new()
+            .name("testcoin")
+            .hrp("test")
+            .magic(0x54455354)
+            .chain_params(params)
+            .build()
+            .unwrap();
+        assert_eq!(zero_spacing.confirmations_equivalent_to_bitcoin_6(), u32::MAX);
+    }
+
+    #[test]
+    fn compact_size_encoded_length() {
+        let n = Network::bitcoin();
+        assert_eq!(n.compact_size_encoded_length(0), 1);
+        assert_eq!(n.compact_size_encoded_length(0xFC), 1);
+        assert_eq!(n.compact_size_encoded_length(0xFD), 3);
+        assert_eq!(n.compact_size_encoded_length(0xFFFF), 3);
+        assert_eq!(n.compact_size_encoded_length(0x10000), 5);
+        assert_eq!(n.compact_size_encoded_length(0xFFFFFFFF), 5);
+        assert_eq!(n.compact_size_encoded_length(0x100000000), 9);
+        assert_eq!(n.compact_size_encoded_length(u64::MAX), 9);
+    }
+
+    #[test]
+    fn taproot_sighash_midstate() {
+        let tag = sha256::Hash::hash(b"TapSighash");
+        let mut engine = sha256::Hash::engine();
+        engine.input(&tag[..]);
+        engine.input(&tag[..]);
+        let midstate = engine.midstate();
+
+        let state = Network::bitcoin().taproot_sighash_sha256_initial_state();
+        for (i, word) in state.iter().enumerate() {
+            let bytes = &midstate[i * 4..i * 4 + 4];
+            let expected = (bytes[0] as u32) << 24 | (bytes[1] as u32) << 16 | (bytes[2] as u32) << 8 | bytes[3] as u32;
+            assert_eq!(*word, expected);
+        }
+    }
+
+    #[test]
+    fn segwit_commitment() {
+        let n = Network::bitcoin();
+        // BIP141: 1-byte - OP_RETURN (0x6a), 1-byte - Push the following 36 bytes (0x24),
+        // 4-byte - Commitment header (0xaa21a9ed), 32-byte - Commitment hash
+        let mut script = vec![0x6a, 0x24, 0xaa, 0x21, 0xa9, 0xed];
+        assert!(!n.segwit_commitment_is_present(&script));
+        script.extend_from_slice(&[0x42; 32]);
+        assert!(n.segwit_commitment_is_present(&script));
+        script[2] = 0;
+        assert!(!n.segwit_commitment_is_present(&script));
+    }
+
+    #[test]
+    fn bip47_x_coordinate() {
+        let n = Network::bitcoin();
+        let mut x = [0xFF; 32];
+        assert!(!n.bip47_x_coordinate_is_valid(&x));
+        x[27] = 0xFE;
+        x[30] = 0xFC;
+        x[31] = 0x2F;
+        assert!(!n.bip47_x_coordinate_is_valid(&x));
+        x[31] = 0x2E;
+        assert!(n.bip47_x_coordinate_is_valid(&x));
+        assert!(n.bip47_x_coordinate_is_valid(&[0; 32]));
+    }
+
+    #[test]
+    fn expected_hashes_per_block() {
+        let n = Network::bitcoin();
+        // difficulty 1: 0x00000000ffff0000000000000000000000000000000000000000000000000000
+        let difficulty_1 = PowTarget([0, 0, 0, 0x00000000ffff0000]);
+        assert_eq!(n.expected_hashes_per_block(&difficulty_1), 0x100010001);
+        assert_eq!(n.expected_hashes_per_block(&PowTarget(n.chain_params().pow_limit)), 1 << 32);
+        assert_eq!(n.expected_hashes_per_block(&PowTarget([u64::MAX; 4])), 1);
+        assert_eq!(n.expected_hashes_per_block(&PowTarget([0; 4])), u128::MAX);
+        assert_eq!(n.expected_hashes_per_block(&PowTarget([0, 0, 1, 0])), u128::MAX);
+        assert_eq!(n.expected_hashes_per_block(&PowTarget([0, 0, 2, 0])), (1 << 127) - 1);
+        assert_eq!(
+            n.expected_hashrate_for_block_time_hashes_per_second(&difficulty_1),
+            0x100010001u64 as f64 / 600.0
+        );
+    }
+
+    #[test]
+    fn pow_limit_compact() {
+        assert_eq!(Network::bitcoin().chain_params().pow_limit_compact(), 0x1d00ffff);
+        assert_eq!(Network::bitcoin_testnet().chain_params().pow_limit_compact(), 0x1d00ffff);
+        assert_eq!(Network::bitcoin_regtest().chain_params().pow_limit_compact(), 0x207fffff);
+        assert_eq!(Network::litecoin().chain_params().pow_limit_compact(), 0x1e0fffff);
+
+        for n in all_networks() {
+            let params = n.chain_params();
+            let compact = params.pow_limit_compact();
+            // the compact form keeps only the three most significant bytes
+            let expanded = PowTarget::from_compact(compact);
+            assert!(expanded.0.iter().rev().le(params.pow_limit.iter().rev()), "{}", n.name());
+            assert_eq!(expanded.to_compact(), compact, "{}", n.name());
+        }
+        assert_eq!(PowTarget::from_compact(0x1d00ffff), PowTarget([0, 0, 0, 0x00000000ffff0000]));
+        assert_eq!(
+            PowTarget::from_compact(0x207fffff),
+            PowTarget([0, 0, 0, 0x7fffff0000000000])
+        );
+    }
+
+    #[test]
+    fn pow_limit_as_big_endian_bytes() {
+        let mut expected = [0xFF; 32];
+        expected[..4].copy_from_slice(&[0; 4]);
+        assert_eq!(Network::bitcoin().chain_params().pow_limit_as_big_endian_bytes(), expected);
+
+        let mut expected = [0xFF; 32];
+        expected[0] = 0x7F;
+        let params = Network::bitcoin_regtest().chain_params();
+        assert_eq!(params.pow_limit_as_big_endian_bytes(), expected);
+    }
+
+    #[test]
+    fn compact_target() {
+        assert_eq!(PowTarget::from_compact(0x01003456), PowTarget([0, 0, 0, 0]));
+        assert_eq!(PowTarget::from_compact(0x02123456), PowTarget([0x1234, 0, 0, 0]));
+        assert_eq!(PowTarget::from_compact(0x04123456), PowTarget([0x12345600, 0, 0, 0]));
+        assert_eq!(PowTarget::from_compact(0x05009234), PowTarget([0x92340000, 0, 0, 0]));
+        assert_eq!(PowTarget::from_compact(0x2112_3456), PowTarget([0, 0, 0, 0x3456 << 48]));
+        assert_eq!(PowTarget::from_compact(0xFF00_0001), PowTarget([0; 4]));
+        assert_eq!(PowTarget([0x80, 0, 0, 0]).to_compact(), 0x02008000);
+        assert_eq!(PowTarget([0x12345600, 0, 0, 0]).to_compact(), 0x04123456);
+        assert_eq!(PowTarget([0; 4]).to_compact(), 0);
+        assert_eq!(PowTarget([u64::MAX; 4]).to_compact(), 0x2100ffff);
+        // bits, in the header of bitcoin block 100000
+        assert_eq!(PowTarget::from_compact(0x1b04864c), PowTarget([0, 0, 0, 0x04864c]));
+    }
+
+    fn serialize_header(header: &GenesisBlockHeader) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(80);
+        bytes.extend_from_slice(&header.version.to_le_bytes());
+        bytes.extend_from_slice(&header.prev_blockhash[..]);
+        bytes.extend_from_slice(&header.merkle_root[..]);
+        bytes.extend_from_slice(&header.time.to_le_bytes());
+        bytes.extend_from_slice(&header.bits.to_le_bytes());
+        bytes.extend_from_slice(&header.nonce.to_le_bytes());
+        bytes
+    }
+
+    #[test]
+    fn genesis_block_header() {
+        let n = Network::bitcoin();
+        let header = n.genesis_block_header();
+        assert_eq!(header.version, 1);
+        assert_eq!(header.prev_blockhash[..], [0; 32]);
+        assert_eq!(header.time, 1231006505);
+        assert_eq!(header.bits, 0x1d00ffff);
+        assert_eq!(header.nonce, 2083236893);
+        let bytes = serialize_header(&header);
+        assert_eq!(bytes.len(), 80);
+        assert_eq!(sha256d::Hash::hash(&bytes), n.genesis_block());
+    }
+
+    #[test]
+    fn genesis_header_fields() {
+        // Only networks whose block hash is the double SHA256 of the header can be checked here.
+        let networks = vec![
+            Network::bitcoin(),
+            Network::bitcoin_testnet(),
+            Network::bitcoin_testnet4(),
+            Network::bitcoin_signet(),
+            Network::bitcoin_signet_custom(vec![0x51]),
+            Network::bitcoin_regtest(),
+            Network::litecoin(),
+            Network::litecoin_testnet(),
+            Network::dogecoin(),
+            Network::dogecoin_testnet(),
+            Network::namecoin(),
+            Network::vertcoin(),
+            Network::vertcoin_testnet(),
+            Network::digibyte(),
+            Network::feathercoin(),
+            Network::feathercoin_testnet(),
+            Network::monacoin(),
+            Network::monacoin_testnet(),
+        ];
+        for n in networks {
+            let header = serialize_header(&n.genesis_block_header());
+            assert_eq!(sha256d::Hash::hash(&header), n.genesis_block(), "{}", n.name());
         }
     }
 }
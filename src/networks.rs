@@ -1,16 +1,29 @@
     //! Contains the networks supported by this crate.
     //!
     //! A network is represented by a zero sized struct which implements `NetworkConstants`. When used
-    //! as function argument they should be passed as `&NetworkConstants` and if returned as result
-    //! they should be boxed `Box<NetworkConstants>`. Since they are zero-sized `Box` will not allocate.
+    //! as function argument they should be passed as `&dyn NetworkConstants` and if returned as result
+    //! they should be boxed `Box<dyn NetworkConstants>`. Since they are zero-sized `Box` will not allocate.
     //!
-    //! The reason for this design decision is to avoid complete matching over some enum implementing
-    //! `NetworkConstants` which would make any expansion of the set of supported networks a breaking
-    //! change.
+    //! `Network` refers to these structs through the `NetworkKind` enum, so creating a `Network` for
+    //! a built-in network doesn't allocate. Networks defined outside of this crate implement
+    //! `NetworkConstants` themselves and are stored as `NetworkKind::Custom`. Adding a network to
+    //! this module adds a `NetworkKind` variant.
+    //!
+    //! Bitcoin has two public test networks: `BitcoinTestnet` is testnet3 and `BitcoinTestnet4` is
+    //! its replacement defined in BIP94. They share address prefixes but not genesis blocks,
+    //! magic or ports.
+    //!
+    //! DigiByte uses multiple mining algorithms, its `pow_limit` is the one of the SHA-256
+    //! algorithm.
 
-use ::{ChainParams, NetworkConstants, NetworkType};
+// `new` deliberately returns `Box<dyn NetworkConstants>` instead of `Self`, see above.
+#![allow(clippy::new_ret_no_self)]
+
+use ::{Bip9Deployment, ChainParams, NetworkConstants, NetworkType};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
 use bitcoin_hashes::hex::FromHex;
-use bitcoin_hashes::sha256d;
+use bitcoin_hashes::{sha256d, Hash};
 
 /// Represents the Bitcoin Mainnet
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -20,36 +33,303 @@ pub struct Bitcoin {}
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct BitcoinTestnet {}
 
+/// Represents the Bitcoin Testnet4 (BIP94).
+///
+/// testnet4 replaces testnet3 (`BitcoinTestnet`) with a new genesis block, magic and ports. It
+/// uses the same address and key prefixes, including the "tb" bech32 hrp, so addresses alone
+/// can't tell the two apart. Its consensus rules close testnet3's "block storm" loophole: the
+/// minimum difficulty exception doesn't apply to the first block of a retarget period, and
+/// retargeting is based on the first block of the period instead of the last one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BitcoinTestnet4 {}
+
+/// Represents the default Bitcoin Signet (BIP325), whose blocks are signed by the key holders of
+/// its challenge script
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BitcoinSignet {}
+
+/// Represents a Bitcoin Signet with its own block signing challenge, e.g. a private signet.
+///
+/// It shares the genesis block and all encoding constants with `BitcoinSignet`, the magic is
+/// derived from the challenge. `name()` doesn't include the challenge, so `Network`s of
+/// different custom signets compare equal, compare `challenge_script()` to tell them apart.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BitcoinCustomSignet {
+    challenge: Vec<u8>,
+}
+
 /// Represents the Bitcoin Regtest network
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct BitcoinRegtest {}
 
+/// Represents the Litecoin Mainnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Litecoin {}
+
+/// Represents the Litecoin Testnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LitecoinTestnet {}
+
+/// Represents the Dogecoin Mainnet.
+///
+/// Dogecoin has no native segwit, `hrp()` returns an empty string.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Dogecoin {}
+
+/// Represents the Dogecoin Testnet.
+///
+/// Dogecoin has no native segwit, `hrp()` returns an empty string.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DogecoinTestnet {}
+
+/// Represents the Namecoin Mainnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Namecoin {}
+
+/// Represents the Vertcoin Mainnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Vertcoin {}
+
+/// Represents the Vertcoin Testnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct VertcoinTestnet {}
+
+/// Represents the Groestlcoin Mainnet.
+///
+/// Groestlcoin hashes block headers with Grøstl instead of double SHA256. `genesis_block()` still
+/// returns a `sha256d::Hash` which contains the Grøstl hash for interoperability.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Groestlcoin {}
+
+/// Represents the Ravencoin Mainnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Ravencoin {}
+
+/// Represents the Ravencoin Testnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RavencoinTestnet {}
+
+/// Represents the DigiByte Mainnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DigiByte {}
+
+/// Represents the Feathercoin Mainnet.
+///
+/// Feathercoin started from Litecoin's genesis block, so both share the same `genesis_block()`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Feathercoin {}
+
+/// Represents the Feathercoin Testnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct FeathercoinTestnet {}
+
+/// Represents the Monacoin Mainnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Monacoin {}
+
+/// Represents the Monacoin Testnet
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MonacoinTestnet {}
+
+/// The bitcoin mainnet as a constant, usable in `static` items without runtime initialization,
+/// e.g. `static NETWORK: Bitcoin = BITCOIN;`. Trait methods can't be evaluated at
+/// compile time, in particular `genesis_block()` parses a hex string at runtime.
+pub const BITCOIN: Bitcoin = Bitcoin {};
+
+/// The bitcoin testnet as a constant, see [`BITCOIN`](constant.BITCOIN.html)
+pub const BITCOIN_TESTNET: BitcoinTestnet = BitcoinTestnet {};
+
+/// The bitcoin testnet4 as a constant, see [`BITCOIN`](constant.BITCOIN.html)
+pub const BITCOIN_TESTNET4: BitcoinTestnet4 = BitcoinTestnet4 {};
+
+/// The default bitcoin signet as a constant, see [`BITCOIN`](constant.BITCOIN.html)
+pub const BITCOIN_SIGNET: BitcoinSignet = BitcoinSignet {};
+
+/// The block signing challenge of the default signet, a 1-of-2 bare multisig script
+pub const DEFAULT_SIGNET_CHALLENGE: [u8; 71] = [
+    0x51, 0x21, 0x03, 0xad, 0x5e, 0x0e, 0xda, 0xd1, 0x8c, 0xb1, 0xf0, 0xfc, 0x0d, 0x28, 0xa3, 0xd4,
+    0xf1, 0xf3, 0xe4, 0x45, 0x64, 0x03, 0x37, 0x48, 0x9a, 0xbb, 0x10, 0x40, 0x4f, 0x2d, 0x1e, 0x08,
+    0x6b, 0xe4, 0x30, 0x21, 0x03, 0x59, 0xef, 0x50, 0x21, 0x96, 0x4f, 0xe2, 0x2d, 0x6f, 0x8e, 0x05,
+    0xb2, 0x46, 0x3c, 0x95, 0x40, 0xce, 0x96, 0x88, 0x3f, 0xe3, 0xb2, 0x78, 0x76, 0x0f, 0x04, 0x8f,
+    0x51, 0x89, 0xf2, 0xe6, 0xc4, 0x52, 0xae,
+];
+
+/// The bitcoin regtest network as a constant, see [`BITCOIN`](constant.BITCOIN.html)
+pub const BITCOIN_REGTEST: BitcoinRegtest = BitcoinRegtest {};
+
 impl Bitcoin {
     /// Create a new `Network` object representing Bitcoin
-    pub fn new() -> Box<NetworkConstants> {
+    pub fn new() -> Box<dyn NetworkConstants> {
         Box::new(Bitcoin {})
     }
 }
 
 impl BitcoinTestnet {
     /// Create a new `Network` object representing BitcoinTestnet
-    pub fn new() -> Box<NetworkConstants> {
+    pub fn new() -> Box<dyn NetworkConstants> {
         Box::new(BitcoinTestnet {})
     }
 }
 
+impl BitcoinTestnet4 {
+    /// Create a new `Network` object representing BitcoinTestnet4
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(BitcoinTestnet4 {})
+    }
+}
+
+impl BitcoinSignet {
+    /// Create a new `Network` object representing BitcoinSignet
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(BitcoinSignet {})
+    }
+}
+
+impl BitcoinCustomSignet {
+    /// Create a new `Network` object representing a signet whose blocks have to satisfy the
+    /// `challenge` script
+    pub fn new(challenge: Vec<u8>) -> Box<dyn NetworkConstants> {
+        Box::new(BitcoinCustomSignet { challenge })
+    }
+}
+
+/// Appends `n` in bitcoin's variable length integer encoding
+fn write_compact_size(buf: &mut Vec<u8>, n: u64) {
+    if n < 0xfd {
+        buf.push(n as u8);
+    } else if n <= 0xffff {
+        buf.push(0xfd);
+        buf.extend_from_slice(&(n as u16).to_le_bytes());
+    } else if n <= 0xffff_ffff {
+        buf.push(0xfe);
+        buf.extend_from_slice(&(n as u32).to_le_bytes());
+    } else {
+        buf.push(0xff);
+        buf.extend_from_slice(&n.to_le_bytes());
+    }
+}
+
 impl BitcoinRegtest {
     /// Create a new `Network` object representing BitcoinRegtest
-    pub fn new() -> Box<NetworkConstants> {
+    pub fn new() -> Box<dyn NetworkConstants> {
         Box::new(BitcoinRegtest {})
     }
 }
 
+impl Litecoin {
+    /// Create a new `Network` object representing Litecoin
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(Litecoin {})
+    }
+}
+
+impl LitecoinTestnet {
+    /// Create a new `Network` object representing LitecoinTestnet
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(LitecoinTestnet {})
+    }
+}
+
+impl Dogecoin {
+    /// Create a new `Network` object representing Dogecoin
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(Dogecoin {})
+    }
+}
+
+impl DogecoinTestnet {
+    /// Create a new `Network` object representing DogecoinTestnet
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(DogecoinTestnet {})
+    }
+}
+
+impl Namecoin {
+    /// Create a new `Network` object representing Namecoin
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(Namecoin {})
+    }
+}
+
+impl Vertcoin {
+    /// Create a new `Network` object representing Vertcoin
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(Vertcoin {})
+    }
+}
+
+impl VertcoinTestnet {
+    /// Create a new `Network` object representing VertcoinTestnet
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(VertcoinTestnet {})
+    }
+}
+
+impl Groestlcoin {
+    /// Create a new `Network` object representing Groestlcoin
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(Groestlcoin {})
+    }
+}
+
+impl Ravencoin {
+    /// Create a new `Network` object representing Ravencoin
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(Ravencoin {})
+    }
+}
+
+impl RavencoinTestnet {
+    /// Create a new `Network` object representing RavencoinTestnet
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(RavencoinTestnet {})
+    }
+}
+
+impl DigiByte {
+    /// Create a new `Network` object representing DigiByte
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(DigiByte {})
+    }
+}
+
+impl Feathercoin {
+    /// Create a new `Network` object representing Feathercoin
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(Feathercoin {})
+    }
+}
+
+impl FeathercoinTestnet {
+    /// Create a new `Network` object representing FeathercoinTestnet
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(FeathercoinTestnet {})
+    }
+}
+
+impl Monacoin {
+    /// Create a new `Network` object representing Monacoin
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(Monacoin {})
+    }
+}
+
+impl MonacoinTestnet {
+    /// Create a new `Network` object representing MonacoinTestnet
+    pub fn new() -> Box<dyn NetworkConstants> {
+        Box::new(MonacoinTestnet {})
+    }
+}
+
 impl NetworkConstants for Bitcoin {
     fn hrp(&self) -> &'static str {
         "bc"
     }
 
+    fn bolt11_hrp(&self) -> &'static str {
+        "lnbc"
+    }
+
     fn p2pk_prefix(&self) -> u8 {
         0
     }
@@ -72,6 +352,46 @@ impl NetworkConstants for Bitcoin {
         &PREFIX
     }
 
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x7C, 0xB2];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x78, 0x78];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99];
+        &PREFIX
+    }
+
     fn wif_prefix(&self) -> u8 {
         128
     }
@@ -84,6 +404,30 @@ impl NetworkConstants for Bitcoin {
         "bitcoin"
     }
 
+    fn currency_name(&self) -> &'static str {
+        "bitcoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Bitcoin Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "BTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".bitcoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Bitcoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
     fn network_type(&self) -> NetworkType {
         NetworkType::Mainnet
     }
@@ -94,6 +438,10 @@ impl NetworkConstants for Bitcoin {
             bip34_height: 227931, // 000000000000024b89b42a942fe0d9fea3bb44ab7bd1b19115dd6a759c0808b8
             bip65_height: 388381, // 000000000000000004c2b624ed5d7756c508d90fd0da2c7c679febfa6c4735f0
             bip66_height: 363725, // 00000000000000000379eaa19dce8c9b722d46ae6a57c2f1a988119488b50931
+            bip68_height: 419328, // 000000000000000004a1b34462cb8aeebd5799177f7a29cf28f2d1961716b5b5
+            csv_height: 419328, // 000000000000000004a1b34462cb8aeebd5799177f7a29cf28f2d1961716b5b5
+            segwit_height: 481824, // 0000000000000000001c8018d9cb3b742ef25114f27563e3fc4a1902167f9893
+            taproot_height: 709632, // 0000000000000000000687bca986194dc2c1f949318629b44bb54ec0a94d8244
             rule_change_activation_threshold: 1916, // 95%
             miner_confirmation_window: 2016,
             pow_limit: [
@@ -106,16 +454,123 @@ impl NetworkConstants for Bitcoin {
             pow_target_timespan: 14 * 24 * 60 * 60, // 2 weeks.
             allow_min_difficulty_blocks: false,
             no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
         }
     }
 
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[
+            Bip9Deployment {
+                name: "csv",
+                bit: 0,
+                start_time: 1462060800, // May 1 2016
+                timeout: 1493596800, // May 1 2017
+                min_activation_height: 0,
+            },
+            Bip9Deployment {
+                name: "segwit",
+                bit: 1,
+                start_time: 1479168000, // Nov 15 2016
+                timeout: 1510704000, // Nov 15 2017
+                min_activation_height: 0,
+            },
+            Bip9Deployment {
+                name: "taproot",
+                bit: 2,
+                start_time: 1619222400, // Apr 24 2021
+                timeout: 1628640000, // Aug 11 2021
+                min_activation_height: 709632,
+            },
+        ]
+    }
+
     fn genesis_block(&self) -> sha256d::Hash {
         sha256d::Hash::from_hex(
             "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f"
         ).expect("static hex string, tested")
     }
 
-    fn clone_boxed(&self) -> Box<NetworkConstants> {
+    fn genesis_block_timestamp(&self) -> u32 {
+        1231006505
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        2083236893
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1d00ffff
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        "000000000000000000000000000000000000000052b2559353df4117b7348b64"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        // block 840000, the fourth halving, pinned for this release
+        Some(
+            sha256d::Hash::from_hex(
+                "0000000000000000000320283a032748cef8227873ff4872689bf23f1cda83a5"
+            ).expect("static hex string, tested")
+        )
+    }
+
+    fn supports_taproot(&self) -> bool {
+        true
+    }
+
+    fn halving_interval(&self) -> u32 {
+        210_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        0
+    }
+
+    fn default_port(&self) -> u16 {
+        8333
+    }
+
+    fn rpc_port(&self) -> u16 {
+        8332
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        2_100_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
         Self::new()
     }
 }
@@ -125,6 +580,10 @@ impl NetworkConstants for BitcoinTestnet {
         "tb"
     }
 
+    fn bolt11_hrp(&self) -> &'static str {
+        "lntb"
+    }
+
     fn p2pk_prefix(&self) -> u8 {
         111
     }
@@ -147,6 +606,46 @@ impl NetworkConstants for BitcoinTestnet {
         &PREFIX
     }
 
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48];
+        &PREFIX
+    }
+
     fn wif_prefix(&self) -> u8 {
         239
     }
@@ -159,6 +658,30 @@ impl NetworkConstants for BitcoinTestnet {
         "bitcoin-testnet"
     }
 
+    fn currency_name(&self) -> &'static str {
+        "bitcoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Bitcoin Testnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "BTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".bitcoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Bitcoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("testnet3")
+    }
+
     fn network_type(&self) -> NetworkType {
         NetworkType::Testnet
     }
@@ -169,6 +692,10 @@ impl NetworkConstants for BitcoinTestnet {
             bip34_height: 21111, // 0000000023b3a96d3484e5abb3755c413e7d41500f8e2a5c3f0dd01299cd8ef8
             bip65_height: 581885, // 00000000007f6655f22f98e72ed80d8b06dc761d5da09df0fa1dc4be4f861eb6
             bip66_height: 330776, // 000000002104c8c45e99a8853285a3b592602a3ccde2b832481da85e9e4ba182
+            bip68_height: 770112, // 00000000025e930139bac5c6c31a403776da130831ab85be56578f3fa75369bb
+            csv_height: 770112, // 00000000025e930139bac5c6c31a403776da130831ab85be56578f3fa75369bb
+            segwit_height: 834624, // 00000000002b980fcd729daaa248fd9316a5200e9b367f4ff2c42453e84201ca
+            taproot_height: 2011968,
             rule_change_activation_threshold: 1512, // 75%
             miner_confirmation_window: 2016,
             pow_limit: [
@@ -181,91 +708,4417 @@ impl NetworkConstants for BitcoinTestnet {
             pow_target_timespan: 14 * 24 * 60 * 60, // 2 weeks.
             allow_min_difficulty_blocks: true,
             no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
         }
     }
 
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[
+            Bip9Deployment {
+                name: "csv",
+                bit: 0,
+                start_time: 1456790400, // Mar 1 2016
+                timeout: 1493596800, // May 1 2017
+                min_activation_height: 0,
+            },
+            Bip9Deployment {
+                name: "segwit",
+                bit: 1,
+                start_time: 1462060800, // May 1 2016
+                timeout: 1493596800, // May 1 2017
+                min_activation_height: 0,
+            },
+            Bip9Deployment {
+                name: "taproot",
+                bit: 2,
+                start_time: 1619222400, // Apr 24 2021
+                timeout: 1628640000, // Aug 11 2021
+                min_activation_height: 0,
+            },
+        ]
+    }
+
     fn genesis_block(&self) -> sha256d::Hash {
         sha256d::Hash::from_hex(
             "000000000933ea01ad0ee984209779baaec3ced90fa3f408719526f8d77f4943"
         ).expect("static hex string, tested")
     }
 
-    fn clone_boxed(&self) -> Box<NetworkConstants> {
-        Self::new()
+    fn genesis_block_timestamp(&self) -> u32 {
+        1296688602
     }
-}
 
-impl NetworkConstants for BitcoinRegtest {
-    fn hrp(&self) -> &'static str {
-        "bcrt"
+    fn genesis_block_nonce(&self) -> u32 {
+        414098458
     }
 
-    fn p2pk_prefix(&self) -> u8 {
-        111
+    fn genesis_block_bits(&self) -> u32 {
+        0x1d00ffff
     }
 
-    fn p2pkh_prefix(&self) -> u8 {
-        111
+    fn genesis_block_version(&self) -> i32 {
+        1
     }
 
-    fn p2sh_prefix(&self) -> u8 {
-        196
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
+        ).expect("static hex string, tested")
     }
 
-    fn xpub_prefix(&self) -> &'static [u8; 4] {
-        static PREFIX: [u8; 4] = [0x04u8, 0x35, 0x87, 0xCF];
-        &PREFIX
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
     }
 
-    fn xpriv_prefix(&self) -> &'static [u8; 4] {
-        static PREFIX: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
-        &PREFIX
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
     }
 
-    fn wif_prefix(&self) -> u8 {
-        239
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        "000000000000000000000000000000000000000000000c59b14e264ba6c15db9"
     }
 
-    fn magic(&self) -> u32 {
-        0xDAB5BFFA
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not pinned yet
     }
 
-    fn name(&self) -> &'static str {
-        "bitcoin-regtest"
+    fn supports_taproot(&self) -> bool {
+        true
     }
 
-    fn network_type(&self) -> NetworkType {
-        NetworkType::Regtest
+    fn halving_interval(&self) -> u32 {
+        210_000
     }
 
-    fn chain_params(&self) -> ChainParams {
-        ChainParams {
-            bip16_time: 1333238400,  // Apr 1 2012
-            bip34_height: 100000000, // not activated on regtest
-            bip65_height: 1351,
-            bip66_height: 1251,                    // used only in rpc tests
-            rule_change_activation_threshold: 108, // 75%
-            miner_confirmation_window: 144,
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        18333
+    }
+
+    fn rpc_port(&self) -> u16 {
+        18332
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        2_100_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for BitcoinTestnet4 {
+    fn hrp(&self) -> &'static str {
+        "tb"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "lntb"
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        196
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04u8, 0x35, 0x87, 0xCF];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        239
+    }
+
+    fn magic(&self) -> u32 {
+        0x283F161C
+    }
+
+    fn name(&self) -> &'static str {
+        "bitcoin-testnet4"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "bitcoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Bitcoin Testnet4"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "BTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".bitcoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Bitcoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("testnet4")
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Testnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400, // Apr 1 2012, before genesis
+            bip34_height: 1,
+            bip65_height: 1,
+            bip66_height: 1,
+            bip68_height: 1,
+            csv_height: 1,
+            segwit_height: 1,
+            taproot_height: 0, // always active on testnet4
+            rule_change_activation_threshold: 1512, // 75%
+            miner_confirmation_window: 2016,
             pow_limit: [
                 0xffffffffffffffffu64,
                 0xffffffffffffffffu64,
                 0xffffffffffffffffu64,
-                0x7fffffffffffffffu64,
+                0x00000000ffffffffu64,
             ],
             pow_target_spacing: 10 * 60,            // 10 minutes.
             pow_target_timespan: 14 * 24 * 60 * 60, // 2 weeks.
             allow_min_difficulty_blocks: true,
-            no_pow_retargeting: true,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
         }
     }
 
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // taproot is always active, earlier soft forks are buried
+    }
+
     fn genesis_block(&self) -> sha256d::Hash {
         sha256d::Hash::from_hex(
-            "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206"
+            "00000000da84f2bafbbc53dee25a72ae507ff4914b867c565be350b0da8bf043"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1714777860
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        393743547
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1d00ffff
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "7aa0a7ae1e223414cb807e40cd57e667b718e42aaf9306db9102fe28912b7b4e"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        "00000000000000000000000000000000000000000000005faa15d02e6202f3ba"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not pinned yet
+    }
+
+    fn supports_taproot(&self) -> bool {
+        true
+    }
+
+    fn halving_interval(&self) -> u32 {
+        210_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        48333
+    }
+
+    fn rpc_port(&self) -> u16 {
+        48332
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        2_100_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for BitcoinSignet {
+    fn hrp(&self) -> &'static str {
+        "tb"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "lntbs"
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        196
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04u8, 0x35, 0x87, 0xCF];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        239
+    }
+
+    fn magic(&self) -> u32 {
+        0x40CF030A
+    }
+
+    fn name(&self) -> &'static str {
+        "bitcoin-signet"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "bitcoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Bitcoin Signet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "BTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".bitcoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Bitcoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("signet")
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Signet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400, // Apr 1 2012, before genesis
+            bip34_height: 1,
+            bip65_height: 1,
+            bip66_height: 1,
+            bip68_height: 1,
+            csv_height: 1,
+            segwit_height: 1,
+            taproot_height: 0, // always active on signet
+            rule_change_activation_threshold: 1815, // 90%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0x0000000000000000u64,
+                0x0000000000000000u64,
+                0x0000000000000000u64,
+                0x00000377ae000000u64,
+            ],
+            pow_target_spacing: 10 * 60,            // 10 minutes.
+            pow_target_timespan: 14 * 24 * 60 * 60, // 2 weeks.
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // taproot is always active, earlier soft forks are buried
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "00000008819873e925422c1ff0f99f7cc9bbb232af63a077a480a3633bee1ef6"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1598918400
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        52613770
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0377ae
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
         ).expect("static hex string, tested")
     }
 
-    fn clone_boxed(&self) -> Box<NetworkConstants> {
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        "000000000000000000000000000000000000000000000000000001ad46be4862"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None
+    }
+
+    fn supports_taproot(&self) -> bool {
+        true
+    }
+
+    fn halving_interval(&self) -> u32 {
+        210_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        38333
+    }
+
+    fn rpc_port(&self) -> u16 {
+        38332
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        2_100_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+
+    fn challenge_script(&self) -> Option<&[u8]> {
+        Some(&DEFAULT_SIGNET_CHALLENGE)
+    }
+}
+
+impl NetworkConstants for BitcoinCustomSignet {
+    fn hrp(&self) -> &'static str {
+        BITCOIN_SIGNET.hrp()
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        BITCOIN_SIGNET.bolt11_hrp()
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        BITCOIN_SIGNET.p2pk_prefix()
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        BITCOIN_SIGNET.p2pkh_prefix()
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        BITCOIN_SIGNET.p2sh_prefix()
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.xpub_prefix()
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.xpriv_prefix()
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.ypub_prefix()
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.ypriv_prefix()
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.zpub_prefix()
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.zpriv_prefix()
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.ypub_multisig_prefix()
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.ypriv_multisig_prefix()
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.zpub_multisig_prefix()
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        BITCOIN_SIGNET.zpriv_multisig_prefix()
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        BITCOIN_SIGNET.wif_prefix()
+    }
+
+    fn magic(&self) -> u32 {
+        let mut script = Vec::with_capacity(self.challenge.len() + 9);
+        write_compact_size(&mut script, self.challenge.len() as u64);
+        script.extend_from_slice(&self.challenge);
+        let hash = sha256d::Hash::hash(&script);
+        u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]])
+    }
+
+    fn name(&self) -> &'static str {
+        "bitcoin-custom-signet"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        BITCOIN_SIGNET.currency_name()
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Bitcoin Custom Signet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        BITCOIN_SIGNET.ticker()
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        BITCOIN_SIGNET.default_datadir_name_unix()
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        BITCOIN_SIGNET.default_datadir_name_windows()
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        BITCOIN_SIGNET.network_subdir_name()
+    }
+
+    fn network_type(&self) -> NetworkType {
+        BITCOIN_SIGNET.network_type()
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        BITCOIN_SIGNET.chain_params()
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        BITCOIN_SIGNET.bip9_deployments()
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        BITCOIN_SIGNET.genesis_block()
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        BITCOIN_SIGNET.genesis_block_timestamp()
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        BITCOIN_SIGNET.genesis_block_nonce()
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        BITCOIN_SIGNET.genesis_block_bits()
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        BITCOIN_SIGNET.genesis_block_version()
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        BITCOIN_SIGNET.genesis_merkle_root()
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        BITCOIN_SIGNET.genesis_block_coinbase_value_satoshis()
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        BITCOIN_SIGNET.genesis_coinbase_is_spendable()
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        BITCOIN_SIGNET.minimum_chain_work_hex()
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        BITCOIN_SIGNET.assumed_valid_block()
+    }
+
+    fn supports_taproot(&self) -> bool {
+        BITCOIN_SIGNET.supports_taproot()
+    }
+
+    fn halving_interval(&self) -> u32 {
+        BITCOIN_SIGNET.halving_interval()
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        BITCOIN_SIGNET.slip44_coin_type()
+    }
+
+    fn default_port(&self) -> u16 {
+        BITCOIN_SIGNET.default_port()
+    }
+
+    fn rpc_port(&self) -> u16 {
+        BITCOIN_SIGNET.rpc_port()
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        BITCOIN_SIGNET.coinbase_maturity()
+    }
+
+    fn max_money(&self) -> u64 {
+        BITCOIN_SIGNET.max_money()
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        BITCOIN_SIGNET.initial_block_subsidy_satoshis()
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Box::new(self.clone())
+    }
+
+    fn challenge_script(&self) -> Option<&[u8]> {
+        Some(&self.challenge)
+    }
+}
+
+impl NetworkConstants for BitcoinRegtest {
+    fn hrp(&self) -> &'static str {
+        "bcrt"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "lnbcrt"
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        196
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04u8, 0x35, 0x87, 0xCF];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        239
+    }
+
+    fn magic(&self) -> u32 {
+        0xDAB5BFFA
+    }
+
+    fn name(&self) -> &'static str {
+        "bitcoin-regtest"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "bitcoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Bitcoin Regtest"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "BTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".bitcoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Bitcoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("regtest")
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Regtest
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,  // Apr 1 2012
+            bip34_height: 100000000, // not activated on regtest
+            bip65_height: 1351,
+            bip66_height: 1251,                    // used only in rpc tests
+            bip68_height: 1, // always active on regtest
+            csv_height: 1, // always active on regtest
+            segwit_height: 0, // always active on regtest
+            taproot_height: 0, // always active on regtest
+            rule_change_activation_threshold: 108, // 75%
+            miner_confirmation_window: 144,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x7fffffffffffffffu64,
+            ],
+            pow_target_spacing: 10 * 60,            // 10 minutes.
+            pow_target_timespan: 14 * 24 * 60 * 60, // 2 weeks.
+            allow_min_difficulty_blocks: true,
+            no_pow_retargeting: true,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // regtest deployments are always active
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1296688602
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        2
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x207fffff
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None
+    }
+
+    fn supports_taproot(&self) -> bool {
+        true
+    }
+
+    fn halving_interval(&self) -> u32 {
+        150
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        18444
+    }
+
+    fn rpc_port(&self) -> u16 {
+        18443
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        2_100_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for Litecoin {
+    fn hrp(&self) -> &'static str {
+        "ltc"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "lnltc"
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        48
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        48
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        50
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x01, 0x9D, 0xA4, 0x62];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x01, 0x9D, 0x9C, 0xFE];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x01, 0xB2, 0x6E, 0xF6]; // Mtub
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x01, 0xB2, 0x67, 0x92]; // Mtpv
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46]; // not in SLIP-0132, wallets use bitcoin's
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C]; // not in SLIP-0132, wallets use bitcoin's
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F]; // not in SLIP-0132, wallets use bitcoin's
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05]; // not in SLIP-0132, wallets use bitcoin's
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3]; // not in SLIP-0132, wallets use bitcoin's
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99]; // not in SLIP-0132, wallets use bitcoin's
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        176
+    }
+
+    fn magic(&self) -> u32 {
+        0xDBB6C0FB
+    }
+
+    fn name(&self) -> &'static str {
+        "litecoin"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "litecoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Litecoin Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "LTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".litecoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Litecoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Mainnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 710000, // fa09d204a83a768ed5a7c8d441fa62f2043abf420cff1226c7b4329aeb9d51cf
+            bip65_height: 918684, // bab3041e8977e0dc3eeff63fe707b92bde1dd449d8efafb248c27c8264cc311a
+            bip66_height: 811879, // 7aceee012833fa8952f8835d8b1b3ae233cd6ab08fdb27a771d2bd7bdc491894
+            bip68_height: 1201536,
+            csv_height: 1201536,
+            segwit_height: 1201536,
+            taproot_height: 2161152,
+            rule_change_activation_threshold: 6048, // 75%
+            miner_confirmation_window: 8064,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 150,                   // 2.5 minutes.
+            pow_target_timespan: 7 * 24 * 60 * 60 / 2, // 3.5 days.
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "12a765e31ffd4059bada1e25190f6e98c99d9714d334efa41a195a7e7e04bfe2"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1317972665
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        2084524493
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "97ddfbbae6be97fd6cdf3e7ca13232a3afff2353e29badfab7f73011edd4ced9"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for litecoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        true
+    }
+
+    fn halving_interval(&self) -> u32 {
+        840_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        2
+    }
+
+    fn default_port(&self) -> u16 {
+        9333
+    }
+
+    fn rpc_port(&self) -> u16 {
+        9332
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        8_400_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for LitecoinTestnet {
+    fn hrp(&self) -> &'static str {
+        "tltc"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "lntltc"
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        58
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        239
+    }
+
+    fn magic(&self) -> u32 {
+        0xF1C8D2FD // message start fd d2 c8 f1
+    }
+
+    fn name(&self) -> &'static str {
+        "litecoin-testnet"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "litecoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Litecoin Testnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "LTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".litecoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Litecoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("testnet4")
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Testnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 76, // 8075c771ed8b495ffd943980a95f702ab34fce3c8c54e379548bda33cc8c0573
+            bip65_height: 76, // 8075c771ed8b495ffd943980a95f702ab34fce3c8c54e379548bda33cc8c0573
+            bip66_height: 76, // 8075c771ed8b495ffd943980a95f702ab34fce3c8c54e379548bda33cc8c0573
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: 16,
+            taproot_height: u32::MAX, // not tracked
+            rule_change_activation_threshold: 1512, // 75%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 150,                   // 2.5 minutes.
+            pow_target_timespan: 7 * 24 * 60 * 60 / 2, // 3.5 days.
+            allow_min_difficulty_blocks: true,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "4966625a4b2851d9fdee139e56211a0d88575f59ed816ff5e6a63deb4e3e29a0"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1486949366
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        293345
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "97ddfbbae6be97fd6cdf3e7ca13232a3afff2353e29badfab7f73011edd4ced9"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for litecoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        true
+    }
+
+    fn halving_interval(&self) -> u32 {
+        840_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        19335
+    }
+
+    fn rpc_port(&self) -> u16 {
+        19332
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        8_400_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for Dogecoin {
+    fn hrp(&self) -> &'static str {
+        ""
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        30
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        30
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        22
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xFA, 0xCA, 0xFD];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xFA, 0xC3, 0x98];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x7C, 0xB2]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x78, 0x78]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        158
+    }
+
+    fn magic(&self) -> u32 {
+        0xC0C0C0C0
+    }
+
+    fn name(&self) -> &'static str {
+        "dogecoin"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "dogecoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Dogecoin Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "DOGE"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".dogecoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Dogecoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Mainnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 1034383,
+            bip65_height: 3464751,
+            bip66_height: 1034383,
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // no segwit
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 9576, // 95%
+            miner_confirmation_window: 10080,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 60,      // 1 minute.
+            pow_target_timespan: 4 * 60, // 4 blocks (DigiShield).
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000, // 4 × the 1 MB maximum block size
+            witness_scale_factor: 1, // no segwit
+            dust_limit_satoshis: 1_000_000, // 0.01 DOGE
+            min_relay_tx_fee_rate: 100_000, // 0.001 DOGE/kvB
+            max_block_sigops: 20_000, // MAX_BLOCK_SIGOPS, no witness scaling
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "1a91e3dace36e2be3bf030a65679fe821aa1d6ef92e7c9902eb318182c355691"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1386325540
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        99943
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "5b2a3f53f605d62c53e62932dac6925e3d74afa5a4b459745c36d42d0ed26a69"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        8_800_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for dogecoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        100_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        3
+    }
+
+    fn default_port(&self) -> u16 {
+        22556
+    }
+
+    fn rpc_port(&self) -> u16 {
+        22555
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        30
+    }
+
+    fn max_money(&self) -> u64 {
+        u64::MAX // uncapped
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        100_000_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for DogecoinTestnet {
+    fn hrp(&self) -> &'static str {
+        ""
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        113
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        113
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        196
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x32, 0xA9, 0xA8];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x32, 0xA2, 0x43];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48]; // no segwit, bitcoin's prefix
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        241
+    }
+
+    fn magic(&self) -> u32 {
+        0xDCB7C1FC // message start fc c1 b7 dc
+    }
+
+    fn name(&self) -> &'static str {
+        "dogecoin-testnet"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "dogecoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Dogecoin Testnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "DOGE"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".dogecoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Dogecoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("testnet3")
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Testnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 708658,
+            bip65_height: 1854705,
+            bip66_height: 708658,
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // no segwit
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 2880, // 75%
+            miner_confirmation_window: 3840,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 60,      // 1 minute.
+            pow_target_timespan: 4 * 60, // 4 blocks (DigiShield).
+            allow_min_difficulty_blocks: true,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000, // 4 × the 1 MB maximum block size
+            witness_scale_factor: 1, // no segwit
+            dust_limit_satoshis: 1_000_000, // 0.01 DOGE
+            min_relay_tx_fee_rate: 100_000, // 0.001 DOGE/kvB
+            max_block_sigops: 20_000, // MAX_BLOCK_SIGOPS, no witness scaling
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "bb0a78264637406b6360aad926284d544d7049f45189db5664f3c4d07350559e"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1391503289
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        997879
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "5b2a3f53f605d62c53e62932dac6925e3d74afa5a4b459745c36d42d0ed26a69"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        8_800_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for dogecoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        100_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        44556
+    }
+
+    fn rpc_port(&self) -> u16 {
+        44555
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        30
+    }
+
+    fn max_money(&self) -> u64 {
+        u64::MAX // uncapped
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        100_000_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for Namecoin {
+    fn hrp(&self) -> &'static str {
+        "nc"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        52
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        52
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        13
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x7C, 0xB2];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x78, 0x78];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        180
+    }
+
+    fn magic(&self) -> u32 {
+        0xFEB4BEF9
+    }
+
+    fn name(&self) -> &'static str {
+        "namecoin"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "namecoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Namecoin Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "NMC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".namecoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Namecoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Mainnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 250000,
+            bip65_height: 388381,
+            bip66_height: 363725,
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // not tracked
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 1916, // 95%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000000ffffffffu64,
+            ],
+            pow_target_spacing: 10 * 60,            // 10 minutes.
+            pow_target_timespan: 14 * 24 * 60 * 60, // 2 weeks.
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "000000000062b72c5e2ceb45fbc8587e807c155b0da735e6483dfba2f0a9c770"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1303000001
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        0xa21ea192
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1c007fff
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "41c62dbd9068c89a449525e3cd5ac61b20ece28c3c38b3f35b2161f0e6d3cb0d"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for namecoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        210_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        7
+    }
+
+    fn default_port(&self) -> u16 {
+        8334
+    }
+
+    fn rpc_port(&self) -> u16 {
+        8336
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        2_100_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for Vertcoin {
+    fn hrp(&self) -> &'static str {
+        "vtc"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        71
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        71
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        5
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x05, 0x35, 0x87, 0xCF];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x05, 0x35, 0x83, 0x94];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x7C, 0xB2];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x78, 0x78];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        199
+    }
+
+    fn magic(&self) -> u32 {
+        0x5652544E
+    }
+
+    fn name(&self) -> &'static str {
+        "vertcoin"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "vertcoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Vertcoin Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "VTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".vertcoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Vertcoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Mainnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 691488,
+            bip65_height: 691488,
+            bip66_height: 691488,
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // not tracked
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 1512, // 75%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 150,                   // 2.5 minutes.
+            pow_target_timespan: 7 * 24 * 60 * 60 / 2, // 3.5 days.
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "4d96a915f49d40b1e5c2844d1ee2dccb90013a990ccea12c492d22110489f0c4"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1389311371
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        5749262
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "4af38ca0e323c0a5226208a73b7589a52c030f234810cf51e13e3249fc0123e7"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for vertcoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        840_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        28
+    }
+
+    fn default_port(&self) -> u16 {
+        5889
+    }
+
+    fn rpc_port(&self) -> u16 {
+        5888
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        8_400_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for VertcoinTestnet {
+    fn hrp(&self) -> &'static str {
+        "tvtc"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        74
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        74
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        196
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        239
+    }
+
+    fn magic(&self) -> u32 {
+        0x74657374
+    }
+
+    fn name(&self) -> &'static str {
+        "vertcoin-testnet"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "vertcoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Vertcoin Testnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "VTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".vertcoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Vertcoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("testnet3")
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Testnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 0,
+            bip65_height: 0,
+            bip66_height: 0,
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // not tracked
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 1512, // 75%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 150,                   // 2.5 minutes.
+            pow_target_timespan: 7 * 24 * 60 * 60 / 2, // 3.5 days.
+            allow_min_difficulty_blocks: true,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "cee8f24feb7a64c8f07916976aa4855decac79b6741a8ec2e32e2747497ad2c9"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1481291250
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        915027
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "4af38ca0e323c0a5226208a73b7589a52c030f234810cf51e13e3249fc0123e7"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for vertcoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        840_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        15889
+    }
+
+    fn rpc_port(&self) -> u16 {
+        15888
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        8_400_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for Groestlcoin {
+    fn hrp(&self) -> &'static str {
+        "grs"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        36
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        36
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        5
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x7C, 0xB2];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x78, 0x78];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        128
+    }
+
+    fn magic(&self) -> u32 {
+        0xD4C7E5F9
+    }
+
+    fn name(&self) -> &'static str {
+        "groestlcoin"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "groestlcoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Groestlcoin Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "GRS"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".groestlcoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Groestlcoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Mainnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 800000,
+            bip65_height: 2464000,
+            bip66_height: 800000,
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // not tracked
+            taproot_height: u32::MAX, // not tracked
+            rule_change_activation_threshold: 1916, // 95%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 60,                 // 1 minute.
+            pow_target_timespan: 14 * 24 * 60 * 60, // 2 weeks.
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "00000ac5927c594d49cc0bdb81759d0da8297eb614683d3acb62f0703b639023"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1395342829
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        220035
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0fffff
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        112
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "3ce968df58f9c8a752306c4b7264afab93149dbc578bd08a42c446caaa6628bb"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        0
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for groestlcoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        true
+    }
+
+    fn halving_interval(&self) -> u32 {
+        u32::MAX // no halvings, the subsidy decreases gradually
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        17
+    }
+
+    fn default_port(&self) -> u16 {
+        1331
+    }
+
+    fn rpc_port(&self) -> u16 {
+        1441
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        10_500_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        51_200_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for Ravencoin {
+    fn hrp(&self) -> &'static str {
+        "rc"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        60
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        60
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        122
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x7C, 0xB2];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x78, 0x78];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        128
+    }
+
+    fn magic(&self) -> u32 {
+        0x4E564152 // message start "RAVN"
+    }
+
+    fn name(&self) -> &'static str {
+        "ravencoin"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "ravencoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Ravencoin Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "RVN"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".raven"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Raven"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Mainnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 0, // active from genesis
+            bip65_height: 0,
+            bip66_height: 0,
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // no segwit
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 1613, // 80%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 60,         // 1 minute.
+            pow_target_timespan: 2016 * 60, // 1.4 days.
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000, // 4 × the 1 MB maximum block size
+            witness_scale_factor: 1, // no segwit
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 20_000, // MAX_BLOCK_SIGOPS, no witness scaling
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "0000006b444bc2f2ffe627be9d9e7e7a0730000870ef6eb6da46c8eae389df90"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1514999494
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        25023712
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e00ffff
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        4
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "28ff00a867739a352523808d301f504bc4547699398d70faf2266a8bae5f3516"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        500_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for ravencoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        2_100_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        175
+    }
+
+    fn default_port(&self) -> u16 {
+        8767
+    }
+
+    fn rpc_port(&self) -> u16 {
+        8766
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        2_100_000_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        500_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for RavencoinTestnet {
+    fn hrp(&self) -> &'static str {
+        "trc"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        196
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        239
+    }
+
+    fn magic(&self) -> u32 {
+        0x544E5652 // message start "RVNT"
+    }
+
+    fn name(&self) -> &'static str {
+        "ravencoin-testnet"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "ravencoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Ravencoin Testnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "RVN"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".raven"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Raven"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("testnet7")
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Testnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 0,
+            bip65_height: 0,
+            bip66_height: 0,
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // no segwit
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 1310, // 65%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 60,         // 1 minute.
+            pow_target_timespan: 2016 * 60, // 1.4 days.
+            allow_min_difficulty_blocks: true,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000, // 4 × the 1 MB maximum block size
+            witness_scale_factor: 1, // no segwit
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 20_000, // MAX_BLOCK_SIGOPS, no witness scaling
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "000000ecfc5e6324a079542221d00e10362bdc894d56500c414060eea8a3ad5a"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1537466400
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        15615880
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e00ffff
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        2
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "28ff00a867739a352523808d301f504bc4547699398d70faf2266a8bae5f3516"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        500_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for ravencoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        2_100_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        18770
+    }
+
+    fn rpc_port(&self) -> u16 {
+        18766
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        2_100_000_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        500_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for DigiByte {
+    fn hrp(&self) -> &'static str {
+        "dgb"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        30
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        30
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        63
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x7C, 0xB2];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x78, 0x78];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        128
+    }
+
+    fn magic(&self) -> u32 {
+        0xDAA5BEFA
+    }
+
+    fn name(&self) -> &'static str {
+        "digibyte"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "digibyte"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "DigiByte Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "DGB"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".digibyte"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "DigiByte"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Mainnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: 4394880,
+            bip65_height: 4394880,
+            bip66_height: 4394880,
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: 4394880,
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 28224, // 70%
+            miner_confirmation_window: 40320,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 60 / 4,             // 15 seconds.
+            pow_target_timespan: 14 * 24 * 60 * 60, // 2 weeks.
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "7497ea1b465eb39f1c8f507bc877078fe016d6fcb6dfad3a64c98dcc6e1e8496"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1389388394
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        2447652
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "72ddd9496b004221ed0557358846d9248ecd4c440ebd28ed901efc18757d0fad"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        800_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for digibyte yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        u32::MAX // no halvings, the subsidy decreases monthly
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        20
+    }
+
+    fn default_port(&self) -> u16 {
+        12024
+    }
+
+    fn rpc_port(&self) -> u16 {
+        14022
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        2_100_000_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        800_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for Feathercoin {
+    fn hrp(&self) -> &'static str {
+        "fc"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        14
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        14
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        5
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x7C, 0xB2];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x78, 0x78];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        142
+    }
+
+    fn magic(&self) -> u32 {
+        0x211A1547
+    }
+
+    fn name(&self) -> &'static str {
+        "feathercoin"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "feathercoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Feathercoin Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "FTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".feathercoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Feathercoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Mainnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: u32::MAX, // not tracked
+            bip65_height: u32::MAX, // not tracked
+            bip66_height: u32::MAX, // not tracked
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // not tracked
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 1512, // 75%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 60,                    // 1 minute.
+            pow_target_timespan: 7 * 24 * 60 * 60 / 2, // 3.5 days.
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "12a765e31ffd4059bada1e25190f6e98c99d9714d334efa41a195a7e7e04bfe2"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1317972665
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        2084524493
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "97ddfbbae6be97fd6cdf3e7ca13232a3afff2353e29badfab7f73011edd4ced9"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for feathercoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        840_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        8
+    }
+
+    fn default_port(&self) -> u16 {
+        9336
+    }
+
+    fn rpc_port(&self) -> u16 {
+        9337
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        33_600_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        20_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for FeathercoinTestnet {
+    fn hrp(&self) -> &'static str {
+        "tf"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        65
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        65
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        196
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        193
+    }
+
+    fn magic(&self) -> u32 {
+        0x716D6F73
+    }
+
+    fn name(&self) -> &'static str {
+        "feathercoin-testnet"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "feathercoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Feathercoin Testnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "FTC"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".feathercoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Feathercoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("testnet3")
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Testnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: u32::MAX, // not tracked
+            bip65_height: u32::MAX, // not tracked
+            bip66_height: u32::MAX, // not tracked
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // not tracked
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 1512, // 75%
+            miner_confirmation_window: 2016,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 60,                    // 1 minute.
+            pow_target_timespan: 7 * 24 * 60 * 60 / 2, // 3.5 days.
+            allow_min_difficulty_blocks: true,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "8e8b634d2f2800398261b7adcfbb6ace490e1746e62123ec2bf8010f9fc98b17"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1396255061
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        3250989159
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "97ddfbbae6be97fd6cdf3e7ca13232a3afff2353e29badfab7f73011edd4ced9"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for feathercoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        840_000
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        19336
+    }
+
+    fn rpc_port(&self) -> u16 {
+        19337
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        33_600_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        20_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for Monacoin {
+    fn hrp(&self) -> &'static str {
+        "mona"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        50
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        50
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        55
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x7C, 0xB2];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x9D, 0x78, 0x78];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x47, 0x46];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0xB2, 0x43, 0x0C];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB4, 0x3F];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x95, 0xB0, 0x05];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7E, 0xD3];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0xAA, 0x7A, 0x99];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        178
+    }
+
+    fn magic(&self) -> u32 {
+        0xDBB6C0FB // message start fb c0 b6 db, same as litecoin
+    }
+
+    fn name(&self) -> &'static str {
+        "monacoin"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "monacoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Monacoin Mainnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "MONA"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".monacoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Monacoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Mainnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: u32::MAX, // not tracked
+            bip65_height: u32::MAX, // not tracked
+            bip66_height: u32::MAX, // not tracked
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // not tracked
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 7560, // 75%
+            miner_confirmation_window: 10080,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 90,       // 1.5 minutes.
+            pow_target_timespan: 95 * 60, // 95 minutes.
+            allow_min_difficulty_blocks: false,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "ff9f1c0116d19de7c9963845e129f9ed1bfc0b376eb54fd7afa42e0d418c8bb6"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1388479472
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        1234534
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "35e405a8a46f4dbc1941727aaf338939323c3b955232d0317f8731fe07ac4ba6"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for monacoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        1_051_200
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        22
+    }
+
+    fn default_port(&self) -> u16 {
+        9401
+    }
+
+    fn rpc_port(&self) -> u16 {
+        9402
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        10_512_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
+        Self::new()
+    }
+}
+
+impl NetworkConstants for MonacoinTestnet {
+    fn hrp(&self) -> &'static str {
+        "tmona"
+    }
+
+    fn bolt11_hrp(&self) -> &'static str {
+        "" // no lightning deployment
+    }
+
+    fn p2pk_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2pkh_prefix(&self) -> u8 {
+        111
+    }
+
+    fn p2sh_prefix(&self) -> u8 {
+        117
+    }
+
+    fn xpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x87, 0xCF];
+        &PREFIX
+    }
+
+    fn xpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x35, 0x83, 0x94];
+        &PREFIX
+    }
+
+    fn ypub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x52, 0x62];
+        &PREFIX
+    }
+
+    fn ypriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x4A, 0x4E, 0x28];
+        &PREFIX
+    }
+
+    fn zpub_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x1C, 0xF6];
+        &PREFIX
+    }
+
+    fn zpriv_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x04, 0x5F, 0x18, 0xBC];
+        &PREFIX
+    }
+
+    fn ypub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x89, 0xEF];
+        &PREFIX
+    }
+
+    fn ypriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x42, 0x85, 0xB5];
+        &PREFIX
+    }
+
+    fn zpub_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x54, 0x83];
+        &PREFIX
+    }
+
+    fn zpriv_multisig_prefix(&self) -> &'static [u8; 4] {
+        static PREFIX: [u8; 4] = [0x02, 0x57, 0x50, 0x48];
+        &PREFIX
+    }
+
+    fn wif_prefix(&self) -> u8 {
+        239
+    }
+
+    fn magic(&self) -> u32 {
+        0xFFC0C0F8
+    }
+
+    fn name(&self) -> &'static str {
+        "monacoin-testnet"
+    }
+
+    fn currency_name(&self) -> &'static str {
+        "monacoin"
+    }
+
+    fn short_display_name(&self) -> &'static str {
+        "Monacoin Testnet"
+    }
+
+    fn ticker(&self) -> &'static str {
+        "MONA"
+    }
+
+    fn default_datadir_name_unix(&self) -> &'static str {
+        ".monacoin"
+    }
+
+    fn default_datadir_name_windows(&self) -> &'static str {
+        "Monacoin"
+    }
+
+    fn network_subdir_name(&self) -> Option<&'static str> {
+        Some("testnet4")
+    }
+
+    fn network_type(&self) -> NetworkType {
+        NetworkType::Testnet
+    }
+
+    fn chain_params(&self) -> ChainParams {
+        ChainParams {
+            bip16_time: 1333238400,                 // Apr 1 2012
+            bip34_height: u32::MAX, // not tracked
+            bip65_height: u32::MAX, // not tracked
+            bip66_height: u32::MAX, // not tracked
+            bip68_height: u32::MAX, // not tracked
+            csv_height: u32::MAX, // not tracked
+            segwit_height: u32::MAX, // not tracked
+            taproot_height: u32::MAX, // no taproot
+            rule_change_activation_threshold: 7560, // 75%
+            miner_confirmation_window: 10080,
+            pow_limit: [
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0xffffffffffffffffu64,
+                0x00000fffffffffffu64,
+            ],
+            pow_target_spacing: 90,       // 1.5 minutes.
+            pow_target_timespan: 95 * 60, // 95 minutes.
+            allow_min_difficulty_blocks: true,
+            no_pow_retargeting: false,
+            max_block_weight: 4_000_000,
+            witness_scale_factor: 4,
+            dust_limit_satoshis: 546,
+            min_relay_tx_fee_rate: 1000,
+            max_block_sigops: 80_000,
+            max_block_size: 1_000_000,
+        }
+    }
+
+    fn bip9_deployments(&self) -> &'static [Bip9Deployment] {
+        &[] // not tracked
+    }
+
+    fn genesis_block(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "a2b106ceba3be0c6d097b2a6a6aacf9d638ba8258ae478158f449c321061e0b2"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_timestamp(&self) -> u32 {
+        1488924140
+    }
+
+    fn genesis_block_nonce(&self) -> u32 {
+        2122860
+    }
+
+    fn genesis_block_bits(&self) -> u32 {
+        0x1e0ffff0
+    }
+
+    fn genesis_block_version(&self) -> i32 {
+        1
+    }
+
+    fn genesis_merkle_root(&self) -> sha256d::Hash {
+        sha256d::Hash::from_hex(
+            "35e405a8a46f4dbc1941727aaf338939323c3b955232d0317f8731fe07ac4ba6"
+        ).expect("static hex string, tested")
+    }
+
+    fn genesis_block_coinbase_value_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn genesis_coinbase_is_spendable(&self) -> bool {
+        false
+    }
+
+    fn minimum_chain_work_hex(&self) -> &'static str {
+        // not pinned for monacoin yet, accepts any chain
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    }
+
+    fn assumed_valid_block(&self) -> Option<sha256d::Hash> {
+        None // not tracked
+    }
+
+    fn supports_taproot(&self) -> bool {
+        false
+    }
+
+    fn halving_interval(&self) -> u32 {
+        1_051_200
+    }
+
+    fn slip44_coin_type(&self) -> u32 {
+        1
+    }
+
+    fn default_port(&self) -> u16 {
+        19403
+    }
+
+    fn rpc_port(&self) -> u16 {
+        19402
+    }
+
+    fn coinbase_maturity(&self) -> u32 {
+        100
+    }
+
+    fn max_money(&self) -> u64 {
+        10_512_000_000_000_000
+    }
+
+    fn initial_block_subsidy_satoshis(&self) -> u64 {
+        5_000_000_000
+    }
+
+    fn clone_boxed(&self) -> Box<dyn NetworkConstants> {
         Self::new()
     }
 }